@@ -0,0 +1,112 @@
+use crate::compiler::transpilation::Program;
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+const MAGIC: &[u8] = b"GPEXBIN\0";
+const COMPRESSED_FLAG: u8 = 1;
+// protects against decompression bombs, far above the size of real programs
+const MAX_DECOMPRESSED_BYTES: u64 = 64 * 1024 * 1024;
+
+#[derive(Debug)]
+pub(crate) struct BinaryHeader {
+    pub(crate) format_version: u32,
+    pub(crate) compiler_version: String,
+    is_compressed: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct SavedPayload<'program> {
+    source_hash: u64,
+    program: &'program Program,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoadedPayload {
+    source_hash: u64,
+    program: Program,
+}
+
+pub(crate) fn is_binary(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+pub(crate) fn encode(
+    program: &Program,
+    format_version: u32,
+    compiler_version: &str,
+    is_compressed: bool,
+) -> Vec<u8> {
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend(format_version.to_le_bytes());
+    bytes.push(if is_compressed { COMPRESSED_FLAG } else { 0 });
+    write_str(&mut bytes, compiler_version);
+    let payload = SavedPayload {
+        source_hash: program.source_hash,
+        program,
+    };
+    #[expect(clippy::unwrap_used)] // binary serialization of the program never fails
+    let payload = postcard::to_stdvec(&payload).unwrap();
+    if is_compressed {
+        let mut encoder = DeflateEncoder::new(bytes, Compression::best());
+        #[expect(clippy::unwrap_used)] // writing in memory never fails
+        encoder.write_all(&payload).unwrap();
+        #[expect(clippy::unwrap_used)] // writing in memory never fails
+        encoder.finish().unwrap()
+    } else {
+        bytes.extend(payload);
+        bytes
+    }
+}
+
+pub(crate) fn decode_header(bytes: &[u8]) -> Option<(BinaryHeader, &[u8])> {
+    let bytes = bytes.strip_prefix(MAGIC)?;
+    let (format_version, bytes) = bytes.split_first_chunk()?;
+    let (&flags, bytes) = bytes.split_first()?;
+    let (compiler_version, bytes) = read_string(bytes)?;
+    let header = BinaryHeader {
+        format_version: u32::from_le_bytes(*format_version),
+        compiler_version,
+        is_compressed: flags & COMPRESSED_FLAG != 0,
+    };
+    Some((header, bytes))
+}
+
+pub(crate) fn decode_program(header: &BinaryHeader, payload: &[u8]) -> Option<Program> {
+    let loaded: LoadedPayload = if header.is_compressed {
+        let mut decompressed = vec![];
+        DeflateDecoder::new(payload)
+            .take(MAX_DECOMPRESSED_BYTES + 1)
+            .read_to_end(&mut decompressed)
+            .ok()?;
+        if decompressed.len() as u64 > MAX_DECOMPRESSED_BYTES {
+            return None;
+        }
+        decode_payload(&decompressed)?
+    } else {
+        decode_payload(payload)?
+    };
+    let mut program = loaded.program;
+    program.source_hash = loaded.source_hash;
+    Some(program)
+}
+
+fn decode_payload(bytes: &[u8]) -> Option<LoadedPayload> {
+    let (payload, remaining) = postcard::take_from_bytes(bytes).ok()?;
+    remaining.is_empty().then_some(payload)
+}
+
+#[expect(clippy::cast_possible_truncation)] // the compiler version is always short
+fn write_str(bytes: &mut Vec<u8>, value: &str) {
+    bytes.extend((value.len() as u32).to_le_bytes());
+    bytes.extend(value.as_bytes());
+}
+
+fn read_string(bytes: &[u8]) -> Option<(String, &[u8])> {
+    let (len, bytes) = bytes.split_first_chunk()?;
+    let len = usize::try_from(u32::from_le_bytes(*len)).ok()?;
+    let (value, bytes) = bytes.split_at_checked(len)?;
+    Some((String::from_utf8(value.to_vec()).ok()?, bytes))
+}
@@ -0,0 +1,160 @@
+use crate::compiler::types::Type;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+pub(crate) const DEBUG_BUFFER_NAME: &str = "d";
+const WORD_BYTES: usize = 4;
+const ASSERT_FAILURE_COUNT_BYTES: usize = 4;
+const ASSERT_FAILURE_BYTES: usize = 4;
+// word count and dropped print count
+const PRINT_COUNTER_BYTES: usize = 8;
+
+/// An `assert` item of a `GPEx` program, checked by the GPU when global variables are initialized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Assertion {
+    /// The message displayed when the assertion fails.
+    pub message: String,
+    /// The path of the `GPEx` source file.
+    pub path: PathBuf,
+    /// The 1-based line of the `assert` keyword in the `GPEx` source file.
+    pub line: usize,
+    /// The 1-based column of the `assert` keyword in the `GPEx` source file.
+    pub column: usize,
+}
+
+/// A `debug_print` item of a `GPEx` program, run by the GPU when global variables are initialized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DebugPrint {
+    /// The format string, where each `{}` placeholder is replaced by an argument value.
+    pub format: String,
+    /// The types of the printed arguments.
+    pub arg_types: Vec<Type>,
+    /// The path of the `GPEx` source file.
+    pub path: PathBuf,
+    /// The 1-based line of the `debug_print` keyword in the `GPEx` source file.
+    pub line: usize,
+    /// The 1-based column of the `debug_print` keyword in the `GPEx` source file.
+    pub column: usize,
+}
+
+// a section is only present in the debug buffer if the program contains the related items,
+// and a failure is recorded at most once per assertion, as assertions are run once
+pub(crate) fn assert_section_size(assert_count: usize) -> usize {
+    if assert_count == 0 {
+        0
+    } else {
+        ASSERT_FAILURE_COUNT_BYTES + ASSERT_FAILURE_BYTES * assert_count
+    }
+}
+
+pub(crate) fn print_section_size(print_count: usize, print_capacity: u32) -> usize {
+    if print_count == 0 {
+        0
+    } else {
+        PRINT_COUNTER_BYTES + WORD_BYTES * print_capacity as usize
+    }
+}
+
+pub(crate) fn buffer_size(assert_count: usize, print_count: usize, print_capacity: u32) -> u64 {
+    (assert_section_size(assert_count) + print_section_size(print_count, print_capacity)) as u64
+}
+
+pub(crate) fn transpile_binding(
+    shader: &mut String,
+    (assert_count, print_count): (usize, usize),
+    print_capacity: u32,
+    binding: usize,
+) {
+    *shader += "struct Debug { ";
+    if assert_count > 0 {
+        _ = write!(
+            shader,
+            "assert_failure_count: u32, assert_failures: array<u32, {assert_count}>, "
+        );
+    }
+    if print_count > 0 {
+        _ = write!(
+            shader,
+            "print_word_count: u32, dropped_print_count: u32, \
+            print_words: array<u32, {print_capacity}>, "
+        );
+    }
+    *shader += "} ";
+    _ = write!(
+        shader,
+        "@group(0) @binding({binding}) var<storage, read_write> {DEBUG_BUFFER_NAME}: Debug; "
+    );
+}
+
+// the failure is only identified by the assertion ID, as assertions are only run at init
+pub(crate) fn transpile_assert_failure(shader: &mut String, assert_id: usize) {
+    _ = write!(
+        shader,
+        "{DEBUG_BUFFER_NAME}.assert_failures[{DEBUG_BUFFER_NAME}.assert_failure_count] \
+        = {assert_id}u; "
+    );
+    _ = write!(shader, "{DEBUG_BUFFER_NAME}.assert_failure_count += 1u; ");
+}
+
+// a print record is made of the print ID followed by the bits of each argument
+pub(crate) fn transpile_print_start(
+    shader: &mut String,
+    print_id: usize,
+    word_count: usize,
+    print_capacity: u32,
+) {
+    _ = write!(
+        shader,
+        "if {DEBUG_BUFFER_NAME}.print_word_count + {word_count}u <= {print_capacity}u {{ "
+    );
+    transpile_print_word_start(shader, 0);
+    _ = write!(shader, "{print_id}u); ");
+}
+
+pub(crate) fn transpile_print_word_start(shader: &mut String, word_index: usize) {
+    _ = write!(
+        shader,
+        "{DEBUG_BUFFER_NAME}.print_words[{DEBUG_BUFFER_NAME}.print_word_count + {word_index}u] \
+        = bitcast<u32>("
+    );
+}
+
+pub(crate) fn transpile_print_end(shader: &mut String, word_count: usize) {
+    _ = write!(
+        shader,
+        "{DEBUG_BUFFER_NAME}.print_word_count += {word_count}u; }} else {{ \
+        {DEBUG_BUFFER_NAME}.dropped_print_count += 1u; }} "
+    );
+}
+
+// returns the ID of each failed assertion
+pub(crate) fn decode_assert_failures(section: &[u8]) -> Vec<usize> {
+    let count = section
+        .first_chunk()
+        .map_or(0, |&count| u32::from_ne_bytes(count) as usize);
+    section
+        .get(ASSERT_FAILURE_COUNT_BYTES..)
+        .unwrap_or_default()
+        .chunks_exact(ASSERT_FAILURE_BYTES)
+        .take(count)
+        .map(|failure| u32::from_ne_bytes(failure.try_into().unwrap_or_default()) as usize)
+        .collect()
+}
+
+// returns the words of the print records and the number of dropped prints
+pub(crate) fn decode_print_words(section: &[u8]) -> (Vec<u32>, u32) {
+    let words: Vec<_> = section
+        .chunks_exact(WORD_BYTES)
+        .map(|word| u32::from_ne_bytes(word.try_into().unwrap_or_default()))
+        .collect();
+    let word_count = words.first().map_or(0, |&count| count as usize);
+    let dropped_count = words.get(1).copied().unwrap_or_default();
+    let record_words = words.get(2..).unwrap_or_default();
+    (
+        record_words[..word_count.min(record_words.len())].to_vec(),
+        dropped_count,
+    )
+}
@@ -0,0 +1,165 @@
+use crate::compiler::transpilation::{Program, ShaderId};
+use crate::utils::logs::{Log, LogInner, LogLevel};
+use crate::utils::shader;
+use naga::back::{glsl, hlsl, msl, spv};
+use naga::proc::BoundsCheckPolicies;
+use naga::valid::ModuleInfo;
+use naga::{Module, ShaderStage};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const GLSL_VERSION: u16 = 450;
+
+/// A shader format that can be emitted from a compiled `GPEx` program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ShaderFormat {
+    /// WebGPU Shading Language.
+    Wgsl,
+    /// SPIR-V binary.
+    SpirV,
+    /// Metal Shading Language.
+    Msl,
+    /// High-Level Shading Language.
+    Hlsl,
+    /// OpenGL Shading Language, with one file per entry point.
+    Glsl,
+}
+
+impl Display for ShaderFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wgsl => write!(f, "WGSL"),
+            Self::SpirV => write!(f, "SPIR-V"),
+            Self::Msl => write!(f, "MSL"),
+            Self::Hlsl => write!(f, "HLSL"),
+            Self::Glsl => write!(f, "GLSL"),
+        }
+    }
+}
+
+pub(crate) fn emit(
+    program: &Program,
+    formats: &[ShaderFormat],
+    folder: &Path,
+) -> Result<Vec<PathBuf>, Vec<Log>> {
+    fs::create_dir_all(folder)
+        .map_err(|error| vec![Log::from_io_error(error, folder, "cannot write")])?;
+    let mut paths = vec![];
+    for (id, code) in program.shaders() {
+        if code.is_empty() {
+            continue;
+        }
+        // shaders are validated during compilation and loading, but may have been modified since
+        let (module, info) = shader::parse(code).map_err(|error| {
+            vec![Log {
+                level: LogLevel::Error,
+                message: format!("internal error: invalid {id} in program"),
+                location: None,
+                inner: vec![LogInner {
+                    level: LogLevel::Info,
+                    message: error.describe(code),
+                    location: None,
+                }],
+            }]
+        })?;
+        for &format in formats {
+            for (file_name, content) in translate(id, format, code, &module, &info)
+                .map_err(|error| vec![emit_error(id, format, &error)])?
+            {
+                let path = folder.join(file_name);
+                fs::write(&path, content)
+                    .map_err(|error| vec![Log::from_io_error(error, &path, "cannot write")])?;
+                paths.push(path);
+            }
+        }
+    }
+    Ok(paths)
+}
+
+fn translate(
+    id: ShaderId,
+    format: ShaderFormat,
+    code: &str,
+    module: &Module,
+    info: &ModuleInfo,
+) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let stem = id.file_stem();
+    Ok(match format {
+        ShaderFormat::Wgsl => vec![(format!("{stem}.wgsl"), code.as_bytes().to_vec())],
+        ShaderFormat::SpirV => {
+            let words = spv::write_vec(module, info, &spv::Options::default(), None)
+                .map_err(|error| error.to_string())?;
+            let bytes = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+            vec![(format!("{stem}.spv"), bytes)]
+        }
+        ShaderFormat::Msl => {
+            let options = msl::Options::default();
+            let pipeline_options = msl::PipelineOptions::default();
+            let (shader, _) = msl::write_string(module, info, &options, &pipeline_options)
+                .map_err(|error| error.to_string())?;
+            vec![(format!("{stem}.metal"), shader.into_bytes())]
+        }
+        ShaderFormat::Hlsl => {
+            let options = hlsl::Options::default();
+            let pipeline_options = hlsl::PipelineOptions::default();
+            let mut shader = String::new();
+            hlsl::Writer::new(&mut shader, &options, &pipeline_options)
+                .write(module, info, None)
+                .map_err(|error| error.to_string())?;
+            vec![(format!("{stem}.hlsl"), shader.into_bytes())]
+        }
+        ShaderFormat::Glsl => module
+            .entry_points
+            .iter()
+            .map(|entry_point| {
+                let shader = translate_glsl(module, info, &entry_point.name, entry_point.stage)?;
+                Ok((
+                    format!("{stem}.{}.glsl", entry_point.name),
+                    shader.into_bytes(),
+                ))
+            })
+            .collect::<Result<_, String>>()?,
+    })
+}
+
+fn translate_glsl(
+    module: &Module,
+    info: &ModuleInfo,
+    entry_point: &str,
+    stage: ShaderStage,
+) -> Result<String, String> {
+    let options = glsl::Options {
+        version: glsl::Version::Desktop(GLSL_VERSION),
+        ..glsl::Options::default()
+    };
+    let pipeline_options = glsl::PipelineOptions {
+        shader_stage: stage,
+        entry_point: entry_point.into(),
+        multiview: None,
+    };
+    let mut shader = String::new();
+    glsl::Writer::new(
+        &mut shader,
+        module,
+        info,
+        &options,
+        &pipeline_options,
+        BoundsCheckPolicies::default(),
+    )
+    .and_then(|mut writer| writer.write())
+    .map_err(|error| error.to_string())?;
+    Ok(shader)
+}
+
+// coverage: off (only reachable in case of transpilation bug)
+fn emit_error(id: ShaderId, format: ShaderFormat, error: &str) -> Log {
+    Log {
+        level: LogLevel::Error,
+        message: format!("internal compiler error: cannot emit {format} for {id}: {error}"),
+        location: None,
+        inner: vec![],
+    }
+}
+// coverage: on
@@ -0,0 +1,147 @@
+use crate::compiler::types::Type;
+use std::fmt::Write;
+
+pub(crate) const INPUT_BUFFER_NAME: &str = "i";
+pub(crate) const TIME_BUFFER_NAME: &str = "c";
+pub(crate) const KEY_WORD_COUNT: usize = 4;
+pub(crate) const GAMEPAD_AXIS_COUNT: usize = 4;
+const U32_BITS: u32 = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExternVariable {
+    Key(u32),
+    MouseX,
+    MouseY,
+    MouseButton(u32),
+    GamepadAxis(usize),
+    FrameIndex,
+    DeltaSeconds,
+    ElapsedSeconds,
+}
+
+pub(crate) const EXTERN_VARIABLES: &[(&str, ExternVariable)] = &[
+    ("key_a", ExternVariable::Key(0)),
+    ("key_b", ExternVariable::Key(1)),
+    ("key_c", ExternVariable::Key(2)),
+    ("key_d", ExternVariable::Key(3)),
+    ("key_e", ExternVariable::Key(4)),
+    ("key_f", ExternVariable::Key(5)),
+    ("key_g", ExternVariable::Key(6)),
+    ("key_h", ExternVariable::Key(7)),
+    ("key_i", ExternVariable::Key(8)),
+    ("key_j", ExternVariable::Key(9)),
+    ("key_k", ExternVariable::Key(10)),
+    ("key_l", ExternVariable::Key(11)),
+    ("key_m", ExternVariable::Key(12)),
+    ("key_n", ExternVariable::Key(13)),
+    ("key_o", ExternVariable::Key(14)),
+    ("key_p", ExternVariable::Key(15)),
+    ("key_q", ExternVariable::Key(16)),
+    ("key_r", ExternVariable::Key(17)),
+    ("key_s", ExternVariable::Key(18)),
+    ("key_t", ExternVariable::Key(19)),
+    ("key_u", ExternVariable::Key(20)),
+    ("key_v", ExternVariable::Key(21)),
+    ("key_w", ExternVariable::Key(22)),
+    ("key_x", ExternVariable::Key(23)),
+    ("key_y", ExternVariable::Key(24)),
+    ("key_z", ExternVariable::Key(25)),
+    ("key_0", ExternVariable::Key(26)),
+    ("key_1", ExternVariable::Key(27)),
+    ("key_2", ExternVariable::Key(28)),
+    ("key_3", ExternVariable::Key(29)),
+    ("key_4", ExternVariable::Key(30)),
+    ("key_5", ExternVariable::Key(31)),
+    ("key_6", ExternVariable::Key(32)),
+    ("key_7", ExternVariable::Key(33)),
+    ("key_8", ExternVariable::Key(34)),
+    ("key_9", ExternVariable::Key(35)),
+    ("key_left", ExternVariable::Key(36)),
+    ("key_right", ExternVariable::Key(37)),
+    ("key_up", ExternVariable::Key(38)),
+    ("key_down", ExternVariable::Key(39)),
+    ("key_space", ExternVariable::Key(40)),
+    ("key_enter", ExternVariable::Key(41)),
+    ("key_escape", ExternVariable::Key(42)),
+    ("key_tab", ExternVariable::Key(43)),
+    ("key_backspace", ExternVariable::Key(44)),
+    ("key_shift", ExternVariable::Key(45)),
+    ("key_control", ExternVariable::Key(46)),
+    ("key_alt", ExternVariable::Key(47)),
+    ("mouse_x", ExternVariable::MouseX),
+    ("mouse_y", ExternVariable::MouseY),
+    ("mouse_left", ExternVariable::MouseButton(0)),
+    ("mouse_right", ExternVariable::MouseButton(1)),
+    ("mouse_middle", ExternVariable::MouseButton(2)),
+    ("gamepad_left_x", ExternVariable::GamepadAxis(0)),
+    ("gamepad_left_y", ExternVariable::GamepadAxis(1)),
+    ("gamepad_right_x", ExternVariable::GamepadAxis(2)),
+    ("gamepad_right_y", ExternVariable::GamepadAxis(3)),
+    ("frame_index", ExternVariable::FrameIndex),
+    ("delta_seconds", ExternVariable::DeltaSeconds),
+    ("elapsed_seconds", ExternVariable::ElapsedSeconds),
+];
+
+impl ExternVariable {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        EXTERN_VARIABLES
+            .iter()
+            .find(|(variable_name, _)| *variable_name == name)
+            .map(|&(_, variable)| variable)
+    }
+
+    pub(crate) fn type_(self) -> Type {
+        match self {
+            Self::Key(_)
+            | Self::MouseX
+            | Self::MouseY
+            | Self::MouseButton(_)
+            | Self::GamepadAxis(_)
+            | Self::FrameIndex => Type::I32,
+            Self::DeltaSeconds | Self::ElapsedSeconds => Type::F32,
+        }
+    }
+
+    pub(crate) fn transpile(self, shader: &mut String) {
+        match self {
+            Self::Key(key) => {
+                let (word, bit) = key_position(key);
+                _ = write!(
+                    shader,
+                    "i32(({INPUT_BUFFER_NAME}.keys[{word}] >> {bit}u) & 1u)"
+                );
+            }
+            Self::MouseX => _ = write!(shader, "{INPUT_BUFFER_NAME}.mouse_x"),
+            Self::MouseY => _ = write!(shader, "{INPUT_BUFFER_NAME}.mouse_y"),
+            Self::MouseButton(button) => {
+                _ = write!(
+                    shader,
+                    "i32(({INPUT_BUFFER_NAME}.mouse_buttons >> {button}u) & 1u)"
+                );
+            }
+            Self::GamepadAxis(axis) => {
+                _ = write!(shader, "{INPUT_BUFFER_NAME}.gamepad_axes[{axis}]");
+            }
+            Self::FrameIndex => _ = write!(shader, "{TIME_BUFFER_NAME}.frame_index"),
+            Self::DeltaSeconds => _ = write!(shader, "{TIME_BUFFER_NAME}.delta_seconds"),
+            Self::ElapsedSeconds => _ = write!(shader, "{TIME_BUFFER_NAME}.elapsed_seconds"),
+        }
+    }
+}
+
+// returns the index of the word containing the key bit and the index of the bit in this word
+pub(crate) fn key_position(key: u32) -> (usize, u32) {
+    (key.div_euclid(U32_BITS) as usize, key.rem_euclid(U32_BITS))
+}
+
+pub(crate) fn transpile_input_struct(shader: &mut String) {
+    _ = write!(
+        shader,
+        "struct Input {{ keys: array<u32, {KEY_WORD_COUNT}>, mouse_x: i32, mouse_y: i32, \
+        mouse_buttons: u32, gamepad_axes: array<i32, {GAMEPAD_AXIS_COUNT}>, }} "
+    );
+}
+
+pub(crate) fn transpile_time_struct(shader: &mut String) {
+    *shader += "struct Time { frame_index: i32, delta_seconds: f32, elapsed_seconds: f32, } ";
+}
@@ -0,0 +1,123 @@
+use crate::compiler::source_map::{SourceMap, SourceMapping};
+use itertools::Itertools;
+
+const INDENT: &str = "    ";
+const COMMENT_PREFIX: &str = "//";
+
+// Formats a shader with one statement per line, and comments giving the `GPEx` items at the
+// origin of each line. Existing comments are replaced.
+pub(crate) fn format(shader: &str, source_map: &SourceMap) -> (String, SourceMap) {
+    let mut formatter = ShaderFormatter {
+        source_map,
+        formatted: String::with_capacity(shader.len() * 2),
+        offsets: vec![0; shader.len() + 1],
+        indent: 0,
+        brackets: vec![],
+        line_start: None,
+    };
+    let mut chars = shader.char_indices().peekable();
+    while let Some((offset, char)) = chars.next() {
+        if shader[offset..].starts_with(COMMENT_PREFIX) {
+            while chars.next_if(|&(_, char)| char != '\n').is_some() {}
+            let next_offset = chars.peek().map_or(shader.len(), |&(offset, _)| offset);
+            formatter.skip(offset, next_offset);
+        } else {
+            let next_offset = chars.peek().map_or(shader.len(), |&(offset, _)| offset);
+            formatter.write(offset, next_offset, char);
+        }
+    }
+    formatter.offsets[shader.len()] = formatter.formatted.len();
+    formatter.end_line(shader.len());
+    let mappings = source_map
+        .mappings
+        .iter()
+        .map(|mapping| SourceMapping {
+            shader_range: formatter.offsets[mapping.shader_range.start]
+                ..formatter.offsets[mapping.shader_range.end],
+            ..mapping.clone()
+        })
+        .collect();
+    (formatter.formatted, SourceMap { mappings })
+}
+
+#[derive(Debug)]
+struct ShaderFormatter<'source_map> {
+    source_map: &'source_map SourceMap,
+    formatted: String,
+    offsets: Vec<usize>,
+    indent: usize,
+    // open brackets, where `<` only opens template argument lists
+    brackets: Vec<char>,
+    line_start: Option<usize>,
+}
+
+impl ShaderFormatter<'_> {
+    fn write(&mut self, offset: usize, next_offset: usize, char: char) {
+        if self.line_start.is_none() && char.is_whitespace() {
+            self.skip(offset, next_offset);
+            return;
+        }
+        if char == '}' {
+            self.indent = self.indent.saturating_sub(1);
+            self.end_line(offset);
+        }
+        if self.line_start.is_none() {
+            self.line_start = Some(offset);
+            self.formatted += &INDENT.repeat(self.indent);
+        }
+        let is_template_start = char == '<' && self.is_after_template_identifier();
+        self.skip(offset, next_offset);
+        self.formatted.push(char);
+        match char {
+            '(' | '[' => self.brackets.push(char),
+            '<' if is_template_start => self.brackets.push(char),
+            ')' | ']' => _ = self.brackets.pop(),
+            '>' if self.brackets.last() == Some(&'<') => _ = self.brackets.pop(),
+            '{' => {
+                self.indent += 1;
+                self.end_line(next_offset);
+            }
+            ';' | ',' | '}' if self.brackets.is_empty() => self.end_line(next_offset),
+            _ => {}
+        }
+    }
+
+    fn is_after_template_identifier(&self) -> bool {
+        let before_identifier = self
+            .formatted
+            .trim_end_matches(|char: char| char.is_alphanumeric() || char == '_');
+        let identifier = &self.formatted[before_identifier.len()..];
+        matches!(
+            identifier,
+            "array" | "atomic" | "bitcast" | "ptr" | "var" | "vec2" | "vec3" | "vec4"
+        ) || identifier.starts_with("mat")
+            || identifier.starts_with("texture_")
+    }
+
+    fn skip(&mut self, offset: usize, next_offset: usize) {
+        for skipped_offset in offset..next_offset {
+            self.offsets[skipped_offset] = self.formatted.len();
+        }
+    }
+
+    fn end_line(&mut self, end_offset: usize) {
+        let Some(line_start) = self.line_start.take() else {
+            return;
+        };
+        let items = self
+            .source_map
+            .mappings
+            .iter()
+            .filter(|mapping| (line_start..end_offset).contains(&mapping.shader_range.start))
+            .map(|mapping| &mapping.item)
+            .unique()
+            .join(", ");
+        if !items.is_empty() {
+            self.formatted += " ";
+            self.formatted += COMMENT_PREFIX;
+            self.formatted += " ";
+            self.formatted += &items;
+        }
+        self.formatted += "\n";
+    }
+}
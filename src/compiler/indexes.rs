@@ -10,8 +10,10 @@ pub(crate) struct Indexes<'items> {
     pub(crate) items: NodeIndex<ItemRef<'items>, false>,
     pub(crate) sources: HashMap<u64, ItemRef<'items>>,
     pub(crate) private_sources: HashMap<u64, ItemRef<'items>>,
+    pub(crate) shadowed_sources: HashMap<u64, Vec<ItemRef<'items>>>,
     pub(crate) item_first_refs: HashMap<u64, Span>,
     pub(crate) constants: HashMap<u64, Constant>,
+    pub(crate) variable_buffer_parts: HashMap<u64, usize>,
 }
 
 impl Indexes<'_> {
@@ -21,8 +23,10 @@ impl Indexes<'_> {
             items: NodeIndex::new(file_count),
             sources: HashMap::default(),
             private_sources: HashMap::default(),
+            shadowed_sources: HashMap::default(),
             item_first_refs: HashMap::default(),
             constants: HashMap::default(),
+            variable_buffer_parts: HashMap::default(),
         }
     }
 }
@@ -0,0 +1,237 @@
+use crate::compiler::debug;
+use crate::compiler::indexes::Indexes;
+use crate::compiler::transpilation;
+use crate::compiler::transpilation::{Buffer, BufferField, BufferPart, Program};
+use crate::language::items::var::VariableDefinition;
+use crate::language::module::Module;
+use crate::utils::logs::{Log, LogInner, LogLevel, LogLocation};
+use crate::utils::reading::ReadFile;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wgpu::Limits;
+
+// the input buffer is the only storage buffer used by shaders in addition to variable buffers
+const EXTERN_STORAGE_BUFFER_COUNT: u32 = 1;
+// the debug buffer is also used by the init shader if the program contains assertions or
+// debug prints
+const DEBUG_STORAGE_BUFFER_COUNT: u32 = 1;
+
+/// The limits of the graphic devices targeted by a compiled program.
+///
+/// Global variables are split across several storage buffers so that the program can run on any
+/// device supporting these limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TargetLimits {
+    /// The maximum size in bytes of a storage buffer binding.
+    pub max_storage_buffer_binding_size: u32,
+    /// The maximum number of storage buffers used by a shader stage.
+    pub max_storage_buffers_per_shader_stage: u32,
+}
+
+impl Default for TargetLimits {
+    fn default() -> Self {
+        Self::from(&Limits::default())
+    }
+}
+
+impl From<&Limits> for TargetLimits {
+    fn from(limits: &Limits) -> Self {
+        Self {
+            max_storage_buffer_binding_size: limits.max_storage_buffer_binding_size,
+            max_storage_buffers_per_shader_stage: limits.max_storage_buffers_per_shader_stage,
+        }
+    }
+}
+
+impl TargetLimits {
+    /// Returns the limits supported by most devices, including older ones.
+    pub fn downlevel() -> Self {
+        Self::from(&Limits::downlevel_defaults())
+    }
+
+    // returns the limits of `self` lower than the ones required by `required`, as
+    // `(name, limit, required_limit)`
+    pub(crate) fn unsupported(self, required: Self) -> Vec<(&'static str, u32, u32)> {
+        [
+            (
+                "max_storage_buffer_binding_size",
+                self.max_storage_buffer_binding_size,
+                required.max_storage_buffer_binding_size,
+            ),
+            (
+                "max_storage_buffers_per_shader_stage",
+                self.max_storage_buffers_per_shader_stage,
+                required.max_storage_buffers_per_shader_stage,
+            ),
+        ]
+        .into_iter()
+        .filter(|&(_, limit, required_limit)| limit < required_limit)
+        .collect()
+    }
+
+    // returns the limits actually needed to run `program`, which may be lower than the limits
+    // targeted during compilation
+    pub(crate) fn required_by(program: &Program) -> Self {
+        let has_debug_buffer = !program.asserts.is_empty() || !program.debug_prints.is_empty();
+        let debug_buffer_size = if has_debug_buffer {
+            debug::buffer_size(
+                program.asserts.len(),
+                program.debug_prints.len(),
+                program.debug_print_capacity,
+            )
+        } else {
+            0
+        };
+        let max_part_size = program
+            .buffer
+            .parts
+            .iter()
+            .map(|part| part.size)
+            .max()
+            .unwrap_or(0);
+        Self {
+            max_storage_buffer_binding_size: u32::try_from(debug_buffer_size)
+                .unwrap_or(u32::MAX)
+                .max(max_part_size),
+            max_storage_buffers_per_shader_stage: u32::try_from(program.buffer.parts.len())
+                .unwrap_or(u32::MAX)
+                + EXTERN_STORAGE_BUFFER_COUNT
+                + if has_debug_buffer {
+                    DEBUG_STORAGE_BUFFER_COUNT
+                } else {
+                    0
+                },
+        }
+    }
+}
+
+// computes the location of each global variable and stores the buffer part of each variable
+// in `indexes`
+pub(crate) fn compute(
+    files: &[ReadFile],
+    modules: &[Module],
+    indexes: &mut Indexes<'_>,
+    limits: TargetLimits,
+) -> Result<Buffer, Vec<Log>> {
+    let max_part_size = limits.max_storage_buffer_binding_size;
+    let debug_buffer_count = if transpilation::has_debug_items(modules) {
+        DEBUG_STORAGE_BUFFER_COUNT
+    } else {
+        0
+    };
+    let max_part_count = limits
+        .max_storage_buffers_per_shader_stage
+        .saturating_sub(EXTERN_STORAGE_BUFFER_COUNT + debug_buffer_count);
+    let mut parts: Vec<BufferPart> = vec![];
+    let mut fields = HashMap::new();
+    let mut errors = vec![];
+    for variable in sorted_variables(modules) {
+        #[expect(clippy::expect_used)] // types checked during validation phase
+        let type_ = variable
+            .type_(indexes)
+            .expect("internal error: unknown variable type");
+        let size = type_.size_bytes();
+        if size > max_part_size {
+            errors.push(limit_error(
+                files,
+                variable,
+                format!(
+                    "variable of `{size}` bytes is larger than the maximum storage buffer size"
+                ),
+                limits,
+                debug_buffer_count,
+            ));
+            continue;
+        }
+        let offset = parts.last().map_or(0, |part| part.offset + part.size);
+        if parts
+            .last()
+            .is_none_or(|part| part.size + size > max_part_size)
+        {
+            if parts.len() == max_part_count as usize {
+                errors.push(limit_error(
+                    files,
+                    variable,
+                    "variable doesn't fit in the storage buffers available for variables".into(),
+                    limits,
+                    debug_buffer_count,
+                ));
+                break;
+            }
+            parts.push(BufferPart { offset, size: 0 });
+        }
+        #[expect(clippy::expect_used)] // a part is always created above
+        let part = parts.last_mut().expect("internal error: no buffer part");
+        part.size += size;
+        indexes
+            .variable_buffer_parts
+            .insert(variable.id, parts.len() - 1);
+        let dot_path = &files[variable.name_span.file_index].dot_path;
+        let field = BufferField {
+            type_,
+            size,
+            offset,
+        };
+        fields.insert(format!("{}:{}", dot_path, variable.name), field);
+    }
+    if errors.is_empty() {
+        Ok(Buffer {
+            size: parts.last().map_or(0, |part| part.offset + part.size),
+            fields,
+            parts,
+        })
+    } else {
+        Err(errors)
+    }
+}
+
+pub(crate) fn sorted_variables(modules: &[Module]) -> impl Iterator<Item = &VariableDefinition> {
+    modules
+        .iter()
+        .flat_map(Module::global_variables)
+        .sorted_unstable_by_key(|variable| variable.id)
+}
+
+fn limit_error(
+    files: &[ReadFile],
+    variable: &VariableDefinition,
+    message: String,
+    limits: TargetLimits,
+    debug_buffer_count: u32,
+) -> Log {
+    let file = &files[variable.name_span.file_index];
+    let debug_buffer_note = if debug_buffer_count > 0 {
+        format!(" and `{debug_buffer_count}` for assertions and debug prints")
+    } else {
+        String::new()
+    };
+    Log {
+        level: LogLevel::Error,
+        message: "program variables exceed the storage buffer limits of the target".into(),
+        location: Some(LogLocation {
+            path: file.fs_path.clone(),
+            code: file.content.clone(),
+            span: variable.name_span.start..variable.name_span.end,
+        }),
+        inner: vec![
+            LogInner {
+                level: LogLevel::Info,
+                message,
+                location: None,
+            },
+            LogInner {
+                level: LogLevel::Info,
+                message: format!(
+                    "target supports storage buffers of at most `{}` bytes, and at most `{}` \
+                    storage buffers per shader including `{EXTERN_STORAGE_BUFFER_COUNT}` for \
+                    extern variables{debug_buffer_note}",
+                    limits.max_storage_buffer_binding_size,
+                    limits.max_storage_buffers_per_shader_stage,
+                ),
+                location: None,
+            },
+        ],
+    }
+}
@@ -1,20 +1,65 @@
+pub(crate) mod binary;
 pub(crate) mod compilation;
 pub(crate) mod constants;
+pub(crate) mod debug;
 pub(crate) mod dependencies;
+pub(crate) mod emission;
+pub(crate) mod externs;
+pub(crate) mod formatting;
 pub(crate) mod indexes;
+pub(crate) mod layout;
 pub(crate) mod prelude;
+pub(crate) mod serialization;
+pub(crate) mod source_map;
 pub(crate) mod transpilation;
+pub(crate) mod types;
 
+use crate::compiler::emission::ShaderFormat;
+use crate::compiler::layout::TargetLimits;
+use crate::compiler::serialization::CompiledFormat;
 use crate::compiler::transpilation::Program;
-use crate::utils::logs::Log;
+use crate::utils::logs::{Log, LogInner, LogLevel};
 use crate::utils::reading;
+use crate::utils::shader;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub(crate) const EXTENSION: &str = "gpex";
 
+/// The options of the `GPEx` compiler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CompileOptions {
+    /// Whether compilation fails in case there are warnings.
+    pub is_warning_treated_as_error: bool,
+    /// The limits of the graphic devices targeted by the compiled program.
+    pub limits: TargetLimits,
+    /// The number of 32-bit words of the debug buffer storing debug print records.
+    ///
+    /// Debug prints are only run when global variables are initialized, and the ones run once
+    /// the buffer is full are dropped.
+    pub debug_print_capacity: u32,
+}
+
+impl CompileOptions {
+    /// The default number of 32-bit words of the debug buffer storing debug print records.
+    pub const DEFAULT_DEBUG_PRINT_CAPACITY: u32 = 1024;
+}
+
+impl Default for CompileOptions {
+    fn default() -> Self {
+        Self {
+            is_warning_treated_as_error: false,
+            limits: TargetLimits::default(),
+            debug_print_capacity: Self::DEFAULT_DEBUG_PRINT_CAPACITY,
+        }
+    }
+}
+
 /// Compiles a `GPEx` project folder.
 ///
+/// The compiled program targets devices supporting [`TargetLimits::default`].
+///
 /// # Errors
 ///
 /// An error is returned in case compilation fails.
@@ -22,7 +67,36 @@ pub fn compile(
     root_path: &Path,
     is_warning_treated_as_error: bool,
 ) -> Result<(Program, Vec<Log>), Vec<Log>> {
-    let mut files = vec![prelude::file()];
+    let options = CompileOptions {
+        is_warning_treated_as_error,
+        ..CompileOptions::default()
+    };
+    compile_with_options(root_path, options)
+}
+
+/// Compiles a `GPEx` project folder with the specified options.
+///
+/// # Errors
+///
+/// An error is returned in case compilation fails, or in case the debug print capacity is zero.
+pub fn compile_with_options(
+    root_path: &Path,
+    options: CompileOptions,
+) -> Result<(Program, Vec<Log>), Vec<Log>> {
+    let CompileOptions {
+        is_warning_treated_as_error,
+        limits,
+        debug_print_capacity,
+    } = options;
+    if debug_print_capacity == 0 {
+        return Err(vec![Log {
+            level: LogLevel::Error,
+            message: "debug print capacity must be at least one word".into(),
+            location: None,
+            inner: vec![],
+        }]);
+    }
+    let mut files = prelude::files();
     files.extend(reading::read(root_path, root_path, EXTENSION)?);
     let modules = compilation::parse(root_path, &files)?;
     let mut indexes = compilation::index(&modules);
@@ -33,18 +107,71 @@ pub fn compile(
         &mut indexes,
         is_warning_treated_as_error,
     )?;
-    let program = transpilation::transpile(&files, &modules, &indexes);
+    let buffer = layout::compute(&files, &modules, &mut indexes, limits)?;
+    let program = transpilation::transpile(
+        &files,
+        &modules,
+        &indexes,
+        buffer,
+        limits,
+        debug_print_capacity,
+    );
+    validate_shaders(&program)?;
     Ok((program, errors))
 }
 
-/// Saves compiled `GPEx` program on disk.
+/// Saves compiled `GPEx` program on disk in the specified format.
 ///
 /// # Errors
 ///
 /// An error is returned in case the compiled program cannot be saved at the specified path.
-pub fn save_compiled(program: &Program, path: &Path) -> Result<(), Vec<Log>> {
-    #[expect(clippy::unwrap_used)] // JSON serialization of the program never fails
-    let serialized = serde_json::to_string(&program).unwrap();
-    fs::write(path, serialized)
+pub fn save_compiled(
+    program: &Program,
+    path: &Path,
+    format: CompiledFormat,
+) -> Result<(), Vec<Log>> {
+    fs::write(path, serialization::serialize(program, format))
         .map_err(|error| vec![Log::from_io_error(error, path, "cannot write")])
 }
+
+/// Writes the shaders of a compiled `GPEx` program in the specified formats.
+///
+/// Each shader is written in `folder` (created if missing), and the paths of the written files
+/// are returned.
+///
+/// # Errors
+///
+/// An error is returned in case a shader of the program is invalid, or cannot be translated or
+/// written.
+pub fn emit_shaders(
+    program: &Program,
+    formats: &[ShaderFormat],
+    folder: &Path,
+) -> Result<Vec<PathBuf>, Vec<Log>> {
+    emission::emit(program, formats, folder)
+}
+
+fn validate_shaders(program: &Program) -> Result<(), Vec<Log>> {
+    let errors: Vec<_> = program
+        .shaders()
+        .filter_map(|(name, code)| {
+            // coverage: off (only reachable in case of transpilation bug, as extern functions are validated with naga)
+            shader::validate(code).err().map(|error| Log {
+                level: LogLevel::Error,
+                message: format!("internal compiler error: invalid generated {name}"),
+                location: None,
+                inner: vec![LogInner {
+                    level: LogLevel::Info,
+                    message: error.describe(code),
+                    location: None,
+                }],
+            })
+            // coverage: on
+        })
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors) // no-coverage (only reachable in case of transpilation bug)
+    }
+}
@@ -3,10 +3,37 @@ use std::path::PathBuf;
 
 pub(crate) const PRELUDE_FILE_INDEX: usize = 0;
 
-pub(crate) fn file() -> ReadFile {
-    ReadFile {
-        content: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/res/prelude.gpex")).into(),
-        fs_path: PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/res/prelude.gpex")),
-        dot_path: "prelude".into(),
-    }
+macro_rules! prelude_module {
+    ($dot_path:literal, $fs_path:literal) => {
+        (
+            $dot_path,
+            $fs_path,
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $fs_path)),
+        )
+    };
+}
+
+// the first module is automatically imported in all modules
+const MODULES: &[(&str, &str, &str)] = &[
+    prelude_module!("prelude", "res/prelude.gpex"),
+    prelude_module!("prelude.input", "res/prelude/input.gpex"),
+    prelude_module!("prelude.math", "res/prelude/math.gpex"),
+    prelude_module!("prelude.random", "res/prelude/random.gpex"),
+];
+
+pub(crate) fn files() -> Vec<ReadFile> {
+    MODULES
+        .iter()
+        .map(|&(dot_path, fs_path, content)| ReadFile {
+            content: content.into(),
+            fs_path: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(fs_path),
+            dot_path: dot_path.into(),
+        })
+        .collect()
+}
+
+pub(crate) fn file_index(dot_path: &str) -> Option<usize> {
+    MODULES
+        .iter()
+        .position(|&(module_dot_path, _, _)| module_dot_path == dot_path)
 }
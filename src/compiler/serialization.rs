@@ -0,0 +1,185 @@
+use crate::compiler::binary;
+use crate::compiler::transpilation::{BufferField, Program};
+use crate::utils::logs::{Log, LogInner, LogLevel};
+use crate::utils::shader;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const FORMAT_VERSION: u32 = 1;
+const COMPILER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Serialize)]
+struct SavedProgram<'program> {
+    format_version: u32,
+    compiler_version: &'static str,
+    source_hash: u64,
+    program: &'program Program,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoadedHeader {
+    format_version: u32,
+    compiler_version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoadedProgram {
+    source_hash: u64,
+    program: Program,
+}
+
+/// The format of a compiled `GPEx` program file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CompiledFormat {
+    /// A human-readable JSON file, useful for inspection.
+    Json,
+    /// A compact binary file.
+    Binary,
+    /// A compact binary file compressed with Deflate.
+    CompressedBinary,
+}
+
+pub(crate) fn serialize(program: &Program, format: CompiledFormat) -> Vec<u8> {
+    match format {
+        CompiledFormat::Json => {
+            let saved = SavedProgram {
+                format_version: FORMAT_VERSION,
+                compiler_version: COMPILER_VERSION,
+                source_hash: program.source_hash,
+                program,
+            };
+            #[expect(clippy::unwrap_used)] // JSON serialization of the program never fails
+            serde_json::to_vec(&saved).unwrap()
+        }
+        CompiledFormat::Binary => binary::encode(program, FORMAT_VERSION, COMPILER_VERSION, false),
+        CompiledFormat::CompressedBinary => {
+            binary::encode(program, FORMAT_VERSION, COMPILER_VERSION, true)
+        }
+    }
+}
+
+pub(crate) fn deserialize(content: &[u8], path: &Path) -> Result<Program, Vec<Log>> {
+    let program = if binary::is_binary(content) {
+        deserialize_binary(content, path)?
+    } else {
+        deserialize_json(content, path)?
+    };
+    let errors: Vec<_> = consistency_errors(&program)
+        .into_iter()
+        .chain(program.shaders().filter_map(|(name, code)| {
+            shader::validate(code)
+                .err()
+                .map(|error| info(format!("invalid {name}: {}", error.describe(code))))
+        }))
+        .collect();
+    if errors.is_empty() {
+        Ok(program)
+    } else {
+        Err(vec![invalid_program_error(path, errors)])
+    }
+}
+
+fn deserialize_json(content: &[u8], path: &Path) -> Result<Program, Vec<Log>> {
+    let header: LoadedHeader =
+        serde_json::from_slice(content).map_err(|_| vec![invalid_program_error(path, vec![])])?;
+    check_format_version(header.format_version, &header.compiler_version, path)?;
+    let loaded: LoadedProgram = serde_json::from_slice(content)
+        .map_err(|error| vec![invalid_program_error(path, vec![info(error.to_string())])])?;
+    let mut program = loaded.program;
+    program.source_hash = loaded.source_hash;
+    Ok(program)
+}
+
+fn deserialize_binary(content: &[u8], path: &Path) -> Result<Program, Vec<Log>> {
+    let corrupted_error = || {
+        vec![invalid_program_error(
+            path,
+            vec![info("binary content is corrupted".into())],
+        )]
+    };
+    let (header, payload) = binary::decode_header(content).ok_or_else(corrupted_error)?;
+    check_format_version(header.format_version, &header.compiler_version, path)?;
+    binary::decode_program(&header, payload).ok_or_else(corrupted_error)
+}
+
+// loaded programs may come from untrusted files, so buffer locations are checked before being
+// used by runners
+fn consistency_errors(program: &Program) -> Vec<LogInner> {
+    let buffer = &program.buffer;
+    let is_in_buffer = |offset: u32, size: u32| {
+        offset
+            .checked_add(size)
+            .is_some_and(|end| end <= buffer.size)
+    };
+    let part_errors = buffer
+        .parts
+        .iter()
+        .enumerate()
+        .filter(|(_, part)| !is_in_buffer(part.offset, part.size))
+        .map(|(index, _)| info(format!("buffer part #{index} is outside of the buffer")));
+    let is_field_in_part = |field: &BufferField| {
+        buffer.part_index(field).is_some_and(|part_index| {
+            let part = buffer.parts[part_index];
+            is_in_buffer(field.offset, field.size)
+                && field.offset + field.size <= part.offset.saturating_add(part.size)
+        })
+    };
+    let field_errors = buffer
+        .fields
+        .iter()
+        .filter(|(_, field)| field.size != field.type_.size_bytes() || !is_field_in_part(field))
+        .map(|(path, _)| {
+            info(format!(
+                "buffer field `{path}` is outside of the buffer parts"
+            ))
+        })
+        .sorted_unstable_by(|first, second| first.message.cmp(&second.message));
+    let source_map_errors = (program.draw_shaders.len() != program.draw_source_maps.len())
+        .then(|| info("draw shaders and draw source maps have different counts".into()));
+    part_errors
+        .chain(field_errors)
+        .chain(source_map_errors)
+        .collect()
+}
+
+fn check_format_version(
+    format_version: u32,
+    compiler_version: &str,
+    path: &Path,
+) -> Result<(), Vec<Log>> {
+    if format_version == FORMAT_VERSION {
+        Ok(())
+    } else {
+        Err(vec![Log {
+            level: LogLevel::Error,
+            message: format!(
+                "compiled program \"{}\" has unsupported format version {format_version}",
+                path.display(),
+            ),
+            location: None,
+            inner: vec![info(format!(
+                "program compiled with gpex {compiler_version}, but format version \
+                {FORMAT_VERSION} is expected by gpex {COMPILER_VERSION}, recompile the program",
+            ))],
+        }])
+    }
+}
+
+fn invalid_program_error(path: &Path, inner: Vec<LogInner>) -> Log {
+    Log {
+        level: LogLevel::Error,
+        message: format!("invalid compiled program \"{}\"", path.display()),
+        location: None,
+        inner,
+    }
+}
+
+fn info(message: String) -> LogInner {
+    LogInner {
+        level: LogLevel::Info,
+        message,
+        location: None,
+    }
+}
@@ -0,0 +1,102 @@
+use crate::utils::logs;
+use crate::utils::parsing::Span;
+use crate::utils::reading::ReadFile;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::path::PathBuf;
+
+/// A mapping from a generated shader to the `GPEx` source code.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SourceMap {
+    /// The mappings, sorted by position in the shader.
+    pub mappings: Vec<SourceMapping>,
+}
+
+impl SourceMap {
+    /// Finds the innermost mapping containing a position in the shader.
+    ///
+    /// `line` and `column` are 1-based, as reported by shader compilers.
+    pub fn find(&self, shader: &str, line: usize, column: usize) -> Option<&SourceMapping> {
+        let offset = offset(shader, line, column)?;
+        self.mappings
+            .iter()
+            .filter(|mapping| mapping.shader_range.contains(&offset))
+            .min_by_key(|mapping| mapping.shader_range.len())
+    }
+}
+
+/// A mapping from a part of a generated shader to the `GPEx` source code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SourceMapping {
+    /// The byte range in the shader.
+    pub shader_range: Range<usize>,
+    /// The path of the `GPEx` source file.
+    pub path: PathBuf,
+    /// The 1-based line in the `GPEx` source file.
+    pub line: usize,
+    /// The 1-based column in the `GPEx` source file.
+    pub column: usize,
+    /// The `GPEx` item at the origin of the shader code, in the format `<module dot path>:<name>`.
+    pub item: String,
+}
+
+#[derive(Debug)]
+pub(crate) struct SourceMapBuilder<'files> {
+    files: &'files [ReadFile],
+    mappings: Vec<SourceMapping>,
+}
+
+impl<'files> SourceMapBuilder<'files> {
+    pub(crate) fn new(files: &'files [ReadFile]) -> Self {
+        Self {
+            files,
+            mappings: vec![],
+        }
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        shader: &mut String,
+        span: Span,
+        name: &str,
+        transpile: impl FnOnce(&mut String),
+    ) {
+        let start = shader.len();
+        transpile(shader);
+        let end = shader.trim_end().len();
+        if end > start {
+            let file = &self.files[span.file_index];
+            let (line, column) = logs::line_column(&file.content, span.start);
+            self.mappings.push(SourceMapping {
+                shader_range: start..end,
+                path: file.fs_path.clone(),
+                line,
+                column,
+                item: format!("{}:{name}", file.dot_path),
+            });
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> SourceMap {
+        self.mappings
+            .sort_by_key(|mapping| (mapping.shader_range.start, mapping.shader_range.end));
+        SourceMap {
+            mappings: self.mappings,
+        }
+    }
+}
+
+pub(crate) fn offset(code: &str, line: usize, column: usize) -> Option<usize> {
+    let line_start = if line == 1 {
+        0
+    } else {
+        code.match_indices('\n').nth(line.checked_sub(2)?)?.0 + 1
+    };
+    code[line_start..]
+        .char_indices()
+        .take_while(|&(_, char)| char != '\n')
+        .nth(column.checked_sub(1)?)
+        .map(|(offset, _)| line_start + offset)
+}
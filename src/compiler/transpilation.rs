@@ -1,92 +1,436 @@
+use crate::compiler::EXTENSION;
+use crate::compiler::debug;
+use crate::compiler::debug::{Assertion, DebugPrint};
 use crate::compiler::dependencies::Dependencies;
+use crate::compiler::externs;
+use crate::compiler::externs::{INPUT_BUFFER_NAME, TIME_BUFFER_NAME};
+use crate::compiler::formatting;
 use crate::compiler::indexes::Indexes;
+use crate::compiler::layout;
+use crate::compiler::layout::TargetLimits;
+use crate::compiler::prelude;
+use crate::compiler::source_map::{SourceMap, SourceMapBuilder};
+use crate::compiler::types::Type;
 use crate::language::items::ItemRef;
+use crate::language::items::draw::DrawDefinition;
 use crate::language::items::var::VariableDefinition;
 use crate::language::module::Module;
+use crate::utils::logs;
+use crate::utils::logs::Log;
+use crate::utils::reading;
 use crate::utils::reading::ReadFile;
 use itertools::Itertools;
 use petgraph::graphmap::DiGraphMap;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write};
+use std::iter;
+use std::path::Path;
 
-pub(crate) const MAIN_BUFFER_NAME: &str = "b";
-const I32_SIZE_BYTES: u32 = 4;
+const MAIN_BUFFER_NAME: &str = "b";
+pub(crate) const TARGET_SIZE_NAME: &str = "t";
+const DRAW_ITEM_NAME: &str = "draw";
+const ASSERT_ITEM_NAME: &str = "assert";
+const DEBUG_PRINT_ITEM_NAME: &str = "debug_print";
+// buffer parts other than the main one are bound after the time buffer
+const TIME_BINDING: usize = 2;
 
 /// A compiled `GPEx` program.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Program {
     /// The buffer storing all global variables.
     pub buffer: Buffer,
-    /// The shader used to initialize all global variables.
+    /// The shader used to initialize all global variables, to check assertions and to run debug
+    /// prints, empty if there is none of these items.
     pub init_shader: String,
+    /// The shaders used to draw on the rendering target, in drawing order.
+    pub draw_shaders: Vec<String>,
+    /// The mapping from `init_shader` to the `GPEx` source code.
+    pub init_source_map: SourceMap,
+    /// The mappings from each of `draw_shaders` to the `GPEx` source code.
+    pub draw_source_maps: Vec<SourceMap>,
+    /// The assertions checked by `init_shader`, indexed by the IDs written in the debug buffer.
+    pub asserts: Vec<Assertion>,
+    /// The debug prints run by `init_shader`, indexed by the IDs written in the debug buffer.
+    pub debug_prints: Vec<DebugPrint>,
+    /// The limits of the graphic devices targeted by the program.
+    pub limits: TargetLimits,
+    /// The number of 32-bit words of the debug buffer storing debug print records during a step.
+    pub debug_print_capacity: u32,
+    /// A hash of the source files used to compile the program.
+    #[serde(skip)]
+    pub source_hash: u64,
 }
 
 /// A buffer in a `GPEx` program.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// The buffer is split into parts stored in separate storage buffers, so that device limits are
+/// respected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Buffer {
     /// The size of the buffer in bytes.
     pub size: u32,
     /// The fields of the buffer.
     pub fields: HashMap<String, BufferField>,
+    /// The contiguous parts of the buffer, in binding order.
+    pub parts: Vec<BufferPart>,
+}
+
+/// A part of a buffer in a `GPEx` program, stored in a separate storage buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BufferPart {
+    /// The offset in bytes of the part inside its buffer.
+    pub offset: u32,
+    /// The size of the part in bytes.
+    pub size: u32,
 }
 
 /// A buffer field in a `GPEx` program.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct BufferField {
+    /// The type of the field.
+    pub type_: Type,
     /// The size of the field in bytes.
     pub size: u32,
     /// The offset in bytes of the field inside its buffer.
     pub offset: u32,
 }
 
-pub(crate) fn transpile(files: &[ReadFile], modules: &[Module], indexes: &Indexes<'_>) -> Program {
+impl Buffer {
+    /// Returns the index of the part containing a field.
+    pub fn part_index(&self, field: &BufferField) -> Option<usize> {
+        self.parts.iter().position(|part| {
+            (part.offset..part.offset.saturating_add(part.size)).contains(&field.offset)
+        })
+    }
+}
+
+impl Program {
+    /// Formats the shaders with indentation and comments giving the `GPEx` items at the origin of
+    /// each line.
+    ///
+    /// Source maps are updated accordingly.
+    pub fn into_pretty(mut self) -> Self {
+        (self.init_shader, self.init_source_map) =
+            formatting::format(&self.init_shader, &self.init_source_map);
+        for (shader, source_map) in self.draw_shaders.iter_mut().zip(&mut self.draw_source_maps) {
+            (*shader, *source_map) = formatting::format(shader, source_map);
+        }
+        self
+    }
+
+    /// Returns whether the program has been compiled from the current content of a `GPEx`
+    /// project folder.
+    ///
+    /// This can be used to detect outdated compiled programs.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the folder cannot be read.
+    pub fn is_compiled_from(&self, root_path: &Path) -> Result<bool, Vec<Log>> {
+        let mut files = prelude::files();
+        files.extend(reading::read(root_path, root_path, EXTENSION)?);
+        Ok(reading::hash(&files) == self.source_hash)
+    }
+
+    pub(crate) fn shaders(&self) -> impl Iterator<Item = (ShaderId, &str)> {
+        iter::once((ShaderId::Init, self.init_shader.as_str())).chain(
+            (self.draw_shaders.iter().enumerate())
+                .map(|(index, shader)| (ShaderId::Draw(index), shader.as_str())),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ShaderId {
+    Init,
+    Draw(usize),
+}
+
+impl Display for ShaderId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Init => write!(f, "init shader"),
+            Self::Draw(index) => write!(f, "draw shader #{index}"),
+        }
+    }
+}
+
+impl ShaderId {
+    pub(crate) fn file_stem(self) -> String {
+        match self {
+            Self::Init => "init".into(),
+            Self::Draw(index) => format!("draw{index}"),
+        }
+    }
+}
+
+pub(crate) fn transpile(
+    files: &[ReadFile],
+    modules: &[Module],
+    indexes: &Indexes<'_>,
+    buffer: Buffer,
+    limits: TargetLimits,
+    print_capacity: u32,
+) -> Program {
     let mut init_shader = String::with_capacity(100);
-    transpile_init(&mut init_shader, modules, indexes);
-    let mut offset = 0;
-    let fields = modules
+    let mut init_source_map = SourceMapBuilder::new(files);
+    if has_global_variables(modules) || has_debug_items(modules) {
+        transpile_init(
+            &mut init_shader,
+            &mut init_source_map,
+            modules,
+            indexes,
+            (&buffer, print_capacity),
+        );
+    }
+    let (draw_shaders, draw_source_maps) = modules
         .iter()
-        .flat_map(Module::global_variables)
-        .sorted_unstable_by_key(|variable| variable.id)
-        .map(|variable| {
-            let dot_path = &files[variable.name_span.file_index].dot_path;
-            let path = format!("{}:{}", dot_path, variable.name);
-            let field = BufferField {
-                size: I32_SIZE_BYTES,
-                offset,
-            };
-            offset += field.size;
-            (path, field)
+        .flat_map(Module::draws)
+        .map(|draw| {
+            let mut shader = String::with_capacity(100);
+            let mut source_map = SourceMapBuilder::new(files);
+            transpile_draw(&mut shader, &mut source_map, draw, modules, indexes);
+            (shader, source_map.finish())
         })
-        .collect::<HashMap<_, _>>();
+        .unzip();
     Program {
-        buffer: Buffer {
-            size: offset,
-            fields,
-        },
+        buffer,
         init_shader,
+        draw_shaders,
+        init_source_map: init_source_map.finish(),
+        draw_source_maps,
+        asserts: asserts(files, modules),
+        debug_prints: debug_prints(files, modules, indexes),
+        limits,
+        debug_print_capacity: print_capacity,
+        source_hash: reading::hash(files),
     }
 }
 
-fn transpile_init(shader: &mut String, modules: &[Module], indexes: &Indexes<'_>) {
-    *shader += "struct Buffer { ";
-    for module in modules {
-        for variable in module.global_variables() {
-            variable.transpile_buffer_field(shader);
-        }
+// name of the WGSL variable bound to a buffer part
+pub(crate) fn buffer_part_name(part_index: usize) -> String {
+    if part_index == 0 {
+        MAIN_BUFFER_NAME.into()
+    } else {
+        format!("{MAIN_BUFFER_NAME}{part_index}")
+    }
+}
+
+fn transpile_init(
+    shader: &mut String,
+    source_map: &mut SourceMapBuilder<'_>,
+    modules: &[Module],
+    indexes: &Indexes<'_>,
+    (buffer, print_capacity): (&Buffer, u32),
+) {
+    transpile_buffer_bindings(shader, source_map, modules, indexes, true);
+    transpile_input_binding(shader);
+    transpile_time_binding(shader);
+    let assert_count = modules.iter().flat_map(Module::asserts).count();
+    let print_count = modules.iter().flat_map(Module::debug_prints).count();
+    if has_debug_items(modules) {
+        debug::transpile_binding(
+            shader,
+            (assert_count, print_count),
+            print_capacity,
+            debug_binding(buffer),
+        );
     }
-    *shader += "} @group(0) @binding(0) var<storage, read_write> ";
-    *shader += MAIN_BUFFER_NAME;
-    *shader += ": Buffer; ";
+    transpile_functions(shader, modules, indexes);
     *shader += "@compute @workgroup_size(1, 1, 1) fn main() { ";
     for variable in sorted_global_variables(modules, indexes) {
-        variable.transpile_buffer_init(shader, indexes);
+        source_map.record(shader, variable.name_span, &variable.name, |shader| {
+            variable.transpile_buffer_init(shader, indexes);
+        });
+    }
+    // assertions are checked once all variables are initialized
+    for (assert_id, assert) in modules.iter().flat_map(Module::asserts).enumerate() {
+        source_map.record(shader, assert.keyword_span, ASSERT_ITEM_NAME, |shader| {
+            assert.transpile(shader, assert_id, indexes);
+        });
+    }
+    for (print_id, print) in modules.iter().flat_map(Module::debug_prints).enumerate() {
+        source_map.record(
+            shader,
+            print.keyword_span,
+            DEBUG_PRINT_ITEM_NAME,
+            |shader| {
+                print.transpile(shader, print_id, print_capacity, indexes);
+            },
+        );
     }
     *shader += "}";
 }
 
+fn transpile_draw(
+    shader: &mut String,
+    source_map: &mut SourceMapBuilder<'_>,
+    draw: &DrawDefinition,
+    modules: &[Module],
+    indexes: &Indexes<'_>,
+) {
+    *shader += "@group(0) @binding(0) var<uniform> ";
+    *shader += TARGET_SIZE_NAME;
+    *shader += ": vec2<u32>; ";
+    transpile_input_binding(shader);
+    transpile_time_binding(shader);
+    if has_global_variables(modules) {
+        transpile_buffer_bindings(shader, source_map, modules, indexes, false);
+    }
+    transpile_functions(shader, modules, indexes);
+    *shader += "struct Vertex { @builtin(position) position: vec4<f32>, ";
+    *shader += "@location(0) color: vec4<f32>, } ";
+    *shader += "@vertex fn vs_main(@builtin(vertex_index) index: u32) -> Vertex { ";
+    *shader += "var corners = array(vec2(0., 0.), vec2(1., 0.), vec2(0., 1.), ";
+    *shader += "vec2(0., 1.), vec2(1., 0.), vec2(1., 1.)); ";
+    *shader += "let rectangle = ";
+    source_map.record(shader, draw.shape_span, DRAW_ITEM_NAME, |shader| {
+        draw.transpile_rectangle(shader, indexes);
+    });
+    *shader += "; let color = ";
+    source_map.record(shader, draw.shape_span, DRAW_ITEM_NAME, |shader| {
+        draw.transpile_color(shader, indexes);
+    });
+    *shader += "; let pixel = rectangle.xy + corners[index] * rectangle.zw; ";
+    *shader += "let position = pixel / vec2<f32>(";
+    *shader += TARGET_SIZE_NAME;
+    *shader += ") * vec2(2., -2.) + vec2(-1., 1.); ";
+    *shader += "return Vertex(vec4(position, 0., 1.), color); } ";
+    *shader += "@fragment fn fs_main(vertex: Vertex) -> @location(0) vec4<f32> { ";
+    *shader += "return vertex.color; }";
+}
+
+fn transpile_input_binding(shader: &mut String) {
+    externs::transpile_input_struct(shader);
+    *shader += "@group(0) @binding(1) var<storage, read> ";
+    *shader += INPUT_BUFFER_NAME;
+    *shader += ": Input; ";
+}
+
+fn transpile_time_binding(shader: &mut String) {
+    externs::transpile_time_struct(shader);
+    *shader += "@group(0) @binding(2) var<uniform> ";
+    *shader += TIME_BUFFER_NAME;
+    *shader += ": Time; ";
+}
+
+// only the extern functions referenced in the program are defined
+fn transpile_functions(shader: &mut String, modules: &[Module], indexes: &Indexes<'_>) {
+    let functions = modules
+        .iter()
+        .flat_map(Module::functions)
+        .filter(|function| indexes.item_first_refs.contains_key(&function.id));
+    for function in functions {
+        function.transpile_definition(shader);
+    }
+}
+
+// the debug buffer is bound after all buffer parts, or in place of the first part if there is none
+fn debug_binding(buffer: &Buffer) -> usize {
+    if buffer.parts.is_empty() {
+        0
+    } else {
+        TIME_BINDING + buffer.parts.len()
+    }
+}
+
+fn asserts(files: &[ReadFile], modules: &[Module]) -> Vec<Assertion> {
+    modules
+        .iter()
+        .flat_map(Module::asserts)
+        .map(|assert| {
+            let file = &files[assert.keyword_span.file_index];
+            let (line, column) = logs::line_column(&file.content, assert.keyword_span.start);
+            Assertion {
+                message: assert.message.clone(),
+                path: file.fs_path.clone(),
+                line,
+                column,
+            }
+        })
+        .collect()
+}
+
+fn debug_prints(files: &[ReadFile], modules: &[Module], indexes: &Indexes<'_>) -> Vec<DebugPrint> {
+    modules
+        .iter()
+        .flat_map(Module::debug_prints)
+        .map(|print| {
+            let file = &files[print.keyword_span.file_index];
+            let (line, column) = logs::line_column(&file.content, print.keyword_span.start);
+            DebugPrint {
+                format: print.format.clone(),
+                arg_types: print.arg_types(indexes),
+                path: file.fs_path.clone(),
+                line,
+                column,
+            }
+        })
+        .collect()
+}
+
+// returns whether the init shader uses the debug buffer
+pub(crate) fn has_debug_items(modules: &[Module]) -> bool {
+    modules
+        .iter()
+        .any(|module| module.asserts().next().is_some() || module.debug_prints().next().is_some())
+}
+
+fn has_global_variables(modules: &[Module]) -> bool {
+    modules
+        .iter()
+        .flat_map(Module::global_variables)
+        .next()
+        .is_some()
+}
+
+// in init shader, first part is bound before extern variables and other parts after them,
+// whereas in draw shaders, all parts are bound after extern variables
+fn transpile_buffer_bindings(
+    shader: &mut String,
+    source_map: &mut SourceMapBuilder<'_>,
+    modules: &[Module],
+    indexes: &Indexes<'_>,
+    is_init: bool,
+) {
+    let variables_by_part = layout::sorted_variables(modules)
+        .chunk_by(|variable| indexes.variable_buffer_parts.get(&variable.id).copied());
+    for (part_index, variables) in &variables_by_part {
+        let Some(part_index) = part_index else {
+            continue; // no-coverage (all variables have a part after layout)
+        };
+        let struct_name = if part_index == 0 {
+            "Buffer".into()
+        } else {
+            format!("Buffer{part_index}")
+        };
+        *shader += "struct ";
+        *shader += &struct_name;
+        *shader += " { ";
+        for variable in variables {
+            source_map.record(shader, variable.name_span, &variable.name, |shader| {
+                variable.transpile_buffer_field(shader, indexes);
+            });
+        }
+        *shader += "} ";
+        let (binding, access) = match (is_init, part_index) {
+            (true, 0) => (0, "read_write"),
+            (true, _) => (TIME_BINDING + part_index, "read_write"),
+            (false, _) => (TIME_BINDING + 1 + part_index, "read"),
+        };
+        _ = write!(
+            shader,
+            "@group(0) @binding({binding}) var<storage, {access}> {}: {struct_name}; ",
+            buffer_part_name(part_index)
+        );
+    }
+}
+
 #[expect(clippy::expect_used)] // circular dependencies checked during validation phase
 fn sorted_global_variables<'items>(
     modules: &'items [Module],
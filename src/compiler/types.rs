@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The type of a `GPEx` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum Type {
+    /// A signed 32-bit integer.
+    I32,
+    /// A 32-bit floating-point number.
+    F32,
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::I32 => write!(f, "i32"),
+            Self::F32 => write!(f, "f32"),
+        }
+    }
+}
+
+impl Type {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "i32" => Some(Self::I32),
+            "f32" => Some(Self::F32),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn size_bytes(self) -> u32 {
+        match self {
+            Self::I32 | Self::F32 => 4,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::compiler::dependencies::Dependencies;
+use crate::compiler::indexes::Indexes;
+use crate::compiler::types::Type;
+use crate::language::expressions::Expression;
+use crate::language::expressions::identifier::Identifier;
+use crate::language::items::ItemRef;
+use crate::language::symbols::{CLOSE_PARENTHESIS_SYMBOL, COMMA_SYMBOL};
+use crate::utils::parsing::{ParseContext, ParseError, Span};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::validators;
+
+#[derive(Debug)]
+pub(crate) struct FunctionCall {
+    pub(crate) span: Span,
+    name: Identifier,
+    args: Vec<Expression>,
+}
+
+impl FunctionCall {
+    // The opening parenthesis is expected to be already parsed
+    pub(crate) fn parse<'context>(
+        context: &mut ParseContext<'context>,
+        name: Identifier,
+    ) -> Result<Self, ParseError<'context>> {
+        let (args, arg_error) = context.parse_many(
+            0,
+            Expression::parse,
+            Some(|context| Span::parse_symbol(context, COMMA_SYMBOL).map(|_| ())),
+        )?;
+        let close_parenthesis =
+            Span::parse_symbol(context, CLOSE_PARENTHESIS_SYMBOL).map_err(|error| {
+                ParseError::merge(&arg_error.into_iter().chain([error]).collect::<Vec<_>>())
+            })?;
+        Ok(Self {
+            span: name.span.until(close_parenthesis),
+            name,
+            args,
+        })
+    }
+
+    pub(crate) fn index(&self, indexes: &mut Indexes<'_>) {
+        self.name.index(indexes);
+        for arg in &self.args {
+            arg.index(indexes);
+        }
+    }
+
+    pub(crate) fn dependencies<'index>(
+        &self,
+        mut dependencies: Dependencies<'index>,
+        indexes: &Indexes<'index>,
+    ) -> Result<Dependencies<'index>, Vec<Span>> {
+        dependencies = self.name.dependencies(dependencies, indexes)?;
+        for arg in &self.args {
+            dependencies = arg.dependencies(dependencies, indexes)?;
+        }
+        Ok(dependencies)
+    }
+
+    pub(crate) fn validate(
+        &self,
+        constant_mark_span: Option<Span>,
+        context: &mut ValidateContext<'_>,
+        indexes: &mut Indexes<'_>,
+    ) -> Result<(), ValidateError> {
+        self.name.validate_source(context, indexes)?;
+        let function =
+            validators::call::check_function(self.name.source(indexes), self.name.span, context)?;
+        if let Some(constant_mark_span) = constant_mark_span {
+            validators::expression::check_constant(false, self.span, constant_mark_span, context)?;
+        }
+        let mut is_arg_invalid = false;
+        for arg in &self.args {
+            if arg.validate(None, context, indexes).is_err() {
+                is_arg_invalid = true;
+            }
+        }
+        if is_arg_invalid {
+            return Err(ValidateError);
+        }
+        validators::call::check_arg_count(function, self.args.len(), self.span, context)?;
+        for (arg, param) in self.args.iter().zip(&function.params) {
+            validators::call::check_arg_type(arg.type_(indexes), arg.span(), param, context)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn type_(&self, indexes: &Indexes<'_>) -> Option<Type> {
+        match self.name.source(indexes) {
+            ItemRef::Function(node) => node.return_type(),
+            ItemRef::Variable(_) | ItemRef::Constant(_) | ItemRef::ExternVariable(_) => {
+                None // no-coverage (invalid call)
+            }
+        }
+    }
+
+    pub(crate) fn transpile(&self, shader: &mut String, indexes: &Indexes<'_>) {
+        self.name.transpile(shader, indexes);
+        *shader += "(";
+        for (index, arg) in self.args.iter().enumerate() {
+            if index > 0 {
+                *shader += ", ";
+            }
+            arg.transpile(shader, indexes);
+        }
+        *shader += ")";
+    }
+}
@@ -1,6 +1,7 @@
 use crate::compiler::constants::Constant;
 use crate::compiler::dependencies::Dependencies;
 use crate::compiler::indexes::Indexes;
+use crate::compiler::types::Type;
 use crate::language::items::ItemRef;
 use crate::language::patterns::IDENTIFIER_PATTERN;
 use crate::utils::indexing::NodeRef;
@@ -12,7 +13,7 @@ use crate::validators;
 pub(crate) struct Identifier {
     id: u64,
     scope: Vec<u64>,
-    span: Span,
+    pub(crate) span: Span,
     slice: String,
 }
 
@@ -45,7 +46,14 @@ impl Identifier {
 
     pub(crate) fn index(&self, indexes: &mut Indexes<'_>) {
         let imports = &mut indexes.imports;
-        if let Some(source) = indexes.items.search(&self.slice, self, imports, false) {
+        let mut sources = indexes.items.search_all(&self.slice, self, imports, false);
+        if let Some(source) = sources.next() {
+            let shadowed_sources = sources
+                .filter(|shadowed| shadowed.file_index() != source.file_index())
+                .collect::<Vec<_>>();
+            if !shadowed_sources.is_empty() {
+                indexes.shadowed_sources.insert(self.id, shadowed_sources);
+            }
             indexes.sources.insert(self.id, source);
             indexes
                 .item_first_refs
@@ -77,30 +85,56 @@ impl Identifier {
         context: &mut ValidateContext<'_>,
         indexes: &Indexes<'_>,
     ) -> Result<(), ValidateError> {
-        validators::identifier::check_found(self, self.span, context, indexes)?;
+        self.validate_source(context, indexes)?;
+        let source = self.source(indexes);
+        validators::identifier::check_value(source, self.span, context)?;
         if let Some(constant_mark_span) = constant_mark_span {
-            validators::identifier::check_constant(
-                self,
+            validators::expression::check_constant(
+                matches!(source, ItemRef::Constant(_)),
                 self.span,
                 constant_mark_span,
                 context,
-                indexes,
             )?;
         }
         Ok(())
     }
 
+    pub(crate) fn validate_source(
+        &self,
+        context: &mut ValidateContext<'_>,
+        indexes: &Indexes<'_>,
+    ) -> Result<(), ValidateError> {
+        validators::identifier::check_found(self, self.span, context, indexes)?;
+        validators::identifier::check_ambiguity(self, self.span, context, indexes);
+        Ok(())
+    }
+
+    pub(crate) fn source<'index>(&self, indexes: &Indexes<'index>) -> ItemRef<'index> {
+        indexes.sources[&self.id]
+    }
+
     pub(crate) fn constant(&self, indexes: &Indexes<'_>) -> Option<Constant> {
-        match indexes.sources[&self.id] {
-            ItemRef::Variable(_) => None, // no-coverage (unused for now)
+        match self.source(indexes) {
+            ItemRef::Variable(_) | ItemRef::ExternVariable(_) | ItemRef::Function(_) => None, // no-coverage (unused for now)
             ItemRef::Constant(node) => Some(node.constant(indexes)),
         }
     }
 
+    pub(crate) fn type_(&self, indexes: &Indexes<'_>) -> Option<Type> {
+        match self.source(indexes) {
+            ItemRef::Variable(node) => node.type_(indexes),
+            ItemRef::Constant(_) => Some(Type::I32),
+            ItemRef::ExternVariable(node) => node.type_(),
+            ItemRef::Function(_) => None, // no-coverage (invalid value)
+        }
+    }
+
     pub(crate) fn transpile(&self, shader: &mut String, indexes: &Indexes<'_>) {
-        match indexes.sources[&self.id] {
-            ItemRef::Variable(node) => node.transpile_ref(shader),
+        match self.source(indexes) {
+            ItemRef::Variable(node) => node.transpile_ref(shader, indexes),
             ItemRef::Constant(node) => node.transpile_ref(shader, indexes),
+            ItemRef::ExternVariable(node) => node.transpile_ref(shader),
+            ItemRef::Function(node) => node.transpile_ref(shader),
         }
     }
 }
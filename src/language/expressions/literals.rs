@@ -8,7 +8,7 @@ use crate::validators;
 #[derive(Debug)]
 pub(crate) struct I32Literal {
     id: u64,
-    span: Span,
+    pub(crate) span: Span,
     cleaned: String,
 }
 
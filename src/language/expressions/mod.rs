@@ -1,11 +1,15 @@
 use crate::compiler::constants::Constant;
 use crate::compiler::dependencies::Dependencies;
 use crate::compiler::indexes::Indexes;
+use crate::compiler::types::Type;
+use crate::language::expressions::call::FunctionCall;
 use crate::language::expressions::literals::I32Literal;
+use crate::language::symbols::OPEN_PARENTHESIS_SYMBOL;
 use crate::utils::parsing::{ParseContext, ParseError, Span};
 use crate::utils::validation::{ValidateContext, ValidateError};
 use identifier::Identifier;
 
+pub(crate) mod call;
 pub(crate) mod identifier;
 pub(crate) mod literals;
 
@@ -13,6 +17,7 @@ pub(crate) mod literals;
 pub(crate) enum Expression {
     I32Literal(I32Literal),
     Identifier(Identifier),
+    FunctionCall(FunctionCall),
 }
 
 impl Expression {
@@ -21,17 +26,37 @@ impl Expression {
     ) -> Result<Self, ParseError<'context>> {
         context.parse_any(&[
             |context| I32Literal::parse(context).map(Self::I32Literal),
-            |context| Identifier::parse(context).map(Self::Identifier),
+            |context| Self::parse_identifier_or_call(context),
         ])
     }
 
+    fn parse_identifier_or_call<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        let identifier = Identifier::parse(context)?;
+        if Span::parse_symbol(context, OPEN_PARENTHESIS_SYMBOL).is_ok() {
+            FunctionCall::parse(context, identifier).map(Self::FunctionCall)
+        } else {
+            Ok(Self::Identifier(identifier))
+        }
+    }
+
     pub(crate) fn index(&self, indexes: &mut Indexes<'_>) {
         match self {
             Self::Identifier(node) => node.index(indexes),
+            Self::FunctionCall(node) => node.index(indexes),
             Self::I32Literal(_) => (),
         }
     }
 
+    pub(crate) fn span(&self) -> Span {
+        match self {
+            Self::I32Literal(node) => node.span,
+            Self::Identifier(node) => node.span,
+            Self::FunctionCall(node) => node.span,
+        }
+    }
+
     pub(crate) fn dependencies<'index>(
         &self,
         dependencies: Dependencies<'index>,
@@ -40,6 +65,7 @@ impl Expression {
         match self {
             Self::I32Literal(_) => Ok(dependencies),
             Self::Identifier(node) => node.dependencies(dependencies, indexes),
+            Self::FunctionCall(node) => node.dependencies(dependencies, indexes),
         }
     }
 
@@ -52,6 +78,15 @@ impl Expression {
         match self {
             Self::I32Literal(node) => node.validate(context, indexes),
             Self::Identifier(node) => node.validate(constant_mark_span, context, indexes),
+            Self::FunctionCall(node) => node.validate(constant_mark_span, context, indexes),
+        }
+    }
+
+    pub(crate) fn type_(&self, indexes: &Indexes<'_>) -> Option<Type> {
+        match self {
+            Self::I32Literal(_) => Some(Type::I32),
+            Self::Identifier(node) => node.type_(indexes),
+            Self::FunctionCall(node) => node.type_(indexes),
         }
     }
 
@@ -59,6 +94,7 @@ impl Expression {
         match self {
             Self::I32Literal(node) => Some(node.constant(indexes).clone()),
             Self::Identifier(node) => node.constant(indexes),
+            Self::FunctionCall(_) => None, // no-coverage (unused for now)
         }
     }
 
@@ -66,6 +102,7 @@ impl Expression {
         match self {
             Self::I32Literal(node) => node.transpile(shader, indexes),
             Self::Identifier(node) => node.transpile(shader, indexes),
+            Self::FunctionCall(node) => node.transpile(shader, indexes),
         }
     }
 }
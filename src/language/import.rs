@@ -1,5 +1,6 @@
 use crate::compiler::EXTENSION;
 use crate::compiler::indexes::Indexes;
+use crate::compiler::prelude;
 use crate::language::patterns::IDENTIFIER_PATTERN;
 use crate::language::symbols::{
     DOT_SYMBOL, IMPORT_KEYWORD, PUB_KEYWORD, SEMICOLON_SYMBOL, TILDE_SYMBOL,
@@ -71,6 +72,22 @@ impl Import {
             .files
             .iter()
             .position(|file| file.fs_path == fs_path)
+            .or_else(|| Self::find_prelude_file_index(context, segments))
+    }
+
+    fn find_prelude_file_index(
+        context: &ParseContext<'_>,
+        segments: &[ImportSegment],
+    ) -> Option<usize> {
+        let dot_path = segments
+            .iter()
+            .map(|&segment| match segment {
+                ImportSegment::Name(span) => Some(context.slice(span)),
+                ImportSegment::Parent(_) => None,
+            })
+            .collect::<Option<Vec<_>>>()?
+            .join(".");
+        prelude::file_index(&dot_path)
     }
 
     pub(crate) fn index<'index>(&'index self, indexes: &mut Indexes<'index>) {
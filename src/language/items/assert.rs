@@ -0,0 +1,64 @@
+use crate::compiler::debug;
+use crate::compiler::indexes::Indexes;
+use crate::language::expressions::Expression;
+use crate::language::patterns::STRING_LITERAL_PATTERN;
+use crate::language::symbols::{
+    ASSERT_KEYWORD, CLOSE_PARENTHESIS_SYMBOL, COMMA_SYMBOL, OPEN_PARENTHESIS_SYMBOL,
+    SEMICOLON_SYMBOL,
+};
+use crate::utils::parsing::{ParseContext, ParseError, Span, SpanProperties};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::validators;
+
+#[derive(Debug)]
+pub(crate) struct AssertDefinition {
+    pub(crate) keyword_span: Span,
+    condition: Expression,
+    pub(crate) message: String,
+}
+
+impl AssertDefinition {
+    pub(crate) fn parse<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        let keyword_span = Span::parse_symbol(context, ASSERT_KEYWORD)?;
+        Span::parse_symbol(context, OPEN_PARENTHESIS_SYMBOL)?;
+        let condition = Expression::parse(context)?;
+        Span::parse_symbol(context, COMMA_SYMBOL)?;
+        let message_span = Span::parse_pattern(context, STRING_LITERAL_PATTERN)?;
+        Span::parse_symbol(context, CLOSE_PARENTHESIS_SYMBOL)?;
+        Span::parse_symbol(context, SEMICOLON_SYMBOL)?;
+        let message = context.slice(message_span);
+        Ok(Self {
+            keyword_span,
+            condition,
+            message: message[1..message.len() - 1].into(),
+        })
+    }
+
+    pub(crate) fn index_refs(&self, indexes: &mut Indexes<'_>) {
+        self.condition.index(indexes);
+    }
+
+    pub(crate) fn validate(
+        &self,
+        context: &mut ValidateContext<'_>,
+        indexes: &mut Indexes<'_>,
+    ) -> Result<(), ValidateError> {
+        self.condition.validate(None, context, indexes)?;
+        validators::assert::check_condition_type(
+            self.condition.type_(indexes),
+            self.condition.span(),
+            context,
+        )
+    }
+
+    // the failure is recorded if the condition is zero
+    pub(crate) fn transpile(&self, shader: &mut String, assert_id: usize, indexes: &Indexes<'_>) {
+        *shader += "if (";
+        self.condition.transpile(shader, indexes);
+        *shader += ") == 0 { ";
+        debug::transpile_assert_failure(shader, assert_id);
+        *shader += "} ";
+    }
+}
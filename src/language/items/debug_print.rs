@@ -0,0 +1,106 @@
+use crate::compiler::debug;
+use crate::compiler::indexes::Indexes;
+use crate::compiler::types::Type;
+use crate::language::expressions::Expression;
+use crate::language::patterns::STRING_LITERAL_PATTERN;
+use crate::language::symbols::{
+    CLOSE_PARENTHESIS_SYMBOL, COMMA_SYMBOL, DEBUG_PRINT_KEYWORD, OPEN_PARENTHESIS_SYMBOL,
+    SEMICOLON_SYMBOL,
+};
+use crate::utils::parsing::{ParseContext, ParseError, Span, SpanProperties};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::validators;
+
+pub(crate) const PLACEHOLDER: &str = "{}";
+
+#[derive(Debug)]
+pub(crate) struct DebugPrintDefinition {
+    pub(crate) keyword_span: Span,
+    pub(crate) format: String,
+    args: Vec<Expression>,
+}
+
+impl DebugPrintDefinition {
+    pub(crate) fn parse<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        let keyword_span = Span::parse_symbol(context, DEBUG_PRINT_KEYWORD)?;
+        Span::parse_symbol(context, OPEN_PARENTHESIS_SYMBOL)?;
+        let format_span = Span::parse_pattern(context, STRING_LITERAL_PATTERN)?;
+        let (args, arg_error) = context.parse_many(
+            0,
+            |context| {
+                Span::parse_symbol(context, COMMA_SYMBOL)?;
+                Expression::parse(context)
+            },
+            None,
+        )?;
+        Span::parse_symbol(context, CLOSE_PARENTHESIS_SYMBOL).map_err(|error| {
+            ParseError::merge(&arg_error.into_iter().chain([error]).collect::<Vec<_>>())
+        })?;
+        Span::parse_symbol(context, SEMICOLON_SYMBOL)?;
+        let format = context.slice(format_span);
+        Ok(Self {
+            keyword_span,
+            format: format[1..format.len() - 1].into(),
+            args,
+        })
+    }
+
+    pub(crate) fn index_refs(&self, indexes: &mut Indexes<'_>) {
+        for arg in &self.args {
+            arg.index(indexes);
+        }
+    }
+
+    pub(crate) fn validate(
+        &self,
+        context: &mut ValidateContext<'_>,
+        indexes: &mut Indexes<'_>,
+    ) -> Result<(), ValidateError> {
+        let mut is_arg_invalid = false;
+        for arg in &self.args {
+            if arg.validate(None, context, indexes).is_err() {
+                is_arg_invalid = true;
+            }
+        }
+        if is_arg_invalid {
+            return Err(ValidateError);
+        }
+        validators::debug_print::check_arg_count(
+            self.format.matches(PLACEHOLDER).count(),
+            self.args.len(),
+            self.keyword_span,
+            context,
+        )
+    }
+
+    #[expect(clippy::expect_used)] // types checked during validation phase
+    pub(crate) fn arg_types(&self, indexes: &Indexes<'_>) -> Vec<Type> {
+        self.args
+            .iter()
+            .map(|arg| {
+                arg.type_(indexes)
+                    .expect("internal error: unknown argument type")
+            })
+            .collect()
+    }
+
+    // the record is dropped if there is not enough space left in the debug buffer
+    pub(crate) fn transpile(
+        &self,
+        shader: &mut String,
+        print_id: usize,
+        print_capacity: u32,
+        indexes: &Indexes<'_>,
+    ) {
+        let word_count = self.args.len() + 1;
+        debug::transpile_print_start(shader, print_id, word_count, print_capacity);
+        for (index, arg) in self.args.iter().enumerate() {
+            debug::transpile_print_word_start(shader, index + 1);
+            arg.transpile(shader, indexes);
+            *shader += "); ";
+        }
+        debug::transpile_print_end(shader, word_count);
+    }
+}
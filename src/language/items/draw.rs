@@ -0,0 +1,84 @@
+use crate::compiler::indexes::Indexes;
+use crate::language::expressions::Expression;
+use crate::language::symbols::{
+    CLOSE_PARENTHESIS_SYMBOL, COMMA_SYMBOL, DRAW_KEYWORD, OPEN_PARENTHESIS_SYMBOL, RECTANGLE_SHAPE,
+    SEMICOLON_SYMBOL,
+};
+use crate::utils::parsing::{ParseContext, ParseError, Span};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::validators;
+
+pub(crate) const RECTANGLE_PARAMS: &[&str] = &["x", "y", "width", "height", "red", "green", "blue"];
+
+#[derive(Debug)]
+pub(crate) struct DrawDefinition {
+    pub(crate) shape_span: Span,
+    args: Vec<Expression>,
+}
+
+impl DrawDefinition {
+    pub(crate) fn parse<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        Span::parse_symbol(context, DRAW_KEYWORD)?;
+        let shape_span = Span::parse_symbol(context, RECTANGLE_SHAPE)?;
+        Span::parse_symbol(context, OPEN_PARENTHESIS_SYMBOL)?;
+        let (args, arg_error) = context.parse_many(
+            0,
+            Expression::parse,
+            Some(|context| Span::parse_symbol(context, COMMA_SYMBOL).map(|_| ())),
+        )?;
+        Span::parse_symbol(context, CLOSE_PARENTHESIS_SYMBOL).map_err(|error| {
+            ParseError::merge(&arg_error.into_iter().chain([error]).collect::<Vec<_>>())
+        })?;
+        Span::parse_symbol(context, SEMICOLON_SYMBOL)?;
+        Ok(Self { shape_span, args })
+    }
+
+    pub(crate) fn index_refs(&self, indexes: &mut Indexes<'_>) {
+        for arg in &self.args {
+            arg.index(indexes);
+        }
+    }
+
+    pub(crate) fn validate(
+        &self,
+        context: &mut ValidateContext<'_>,
+        indexes: &mut Indexes<'_>,
+    ) -> Result<(), ValidateError> {
+        let mut is_arg_invalid = false;
+        for arg in &self.args {
+            if arg.validate(None, context, indexes).is_err() {
+                is_arg_invalid = true;
+            }
+        }
+        if is_arg_invalid {
+            return Err(ValidateError);
+        }
+        // all types are numeric, so any argument type is accepted
+        validators::draw::check_arg_count(self.args.len(), self.shape_span, context)
+    }
+
+    pub(crate) fn transpile_rectangle(&self, shader: &mut String, indexes: &Indexes<'_>) {
+        *shader += "vec4<f32>(";
+        for (index, arg) in self.args[..4].iter().enumerate() {
+            if index > 0 {
+                *shader += ", ";
+            }
+            *shader += "f32(";
+            arg.transpile(shader, indexes);
+            *shader += ")";
+        }
+        *shader += ")";
+    }
+
+    pub(crate) fn transpile_color(&self, shader: &mut String, indexes: &Indexes<'_>) {
+        *shader += "vec4<f32>(";
+        for arg in &self.args[4..] {
+            *shader += "f32(";
+            arg.transpile(shader, indexes);
+            *shader += "), ";
+        }
+        *shader += "255.) / 255.";
+    }
+}
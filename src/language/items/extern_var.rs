@@ -0,0 +1,108 @@
+use crate::compiler::externs::ExternVariable;
+use crate::compiler::indexes::Indexes;
+use crate::compiler::types::Type;
+use crate::language::expressions::Expression;
+use crate::language::items::ItemRef;
+use crate::language::patterns::IDENTIFIER_PATTERN;
+use crate::language::symbols::{
+    COLON_SYMBOL, EQUAL_SYMBOL, EXTERN_KEYWORD, PUB_KEYWORD, SEMICOLON_SYMBOL, VAR_KEYWORD,
+};
+use crate::language::type_::TypeReference;
+use crate::utils::parsing::{ParseContext, ParseError, Span, SpanProperties};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::validators;
+
+#[derive(Debug)]
+#[derive_where::derive_where(PartialEq, Eq, Hash)]
+pub(crate) struct ExternVariableDefinition {
+    pub(crate) id: u64,
+    #[derive_where(skip)]
+    pub(crate) scope: Vec<u64>,
+    #[derive_where(skip)]
+    pub(crate) pub_keyword_span: Option<Span>,
+    #[derive_where(skip)]
+    pub(crate) name_span: Span,
+    #[derive_where(skip)]
+    pub(crate) name: String,
+    #[derive_where(skip)]
+    type_: TypeReference,
+    // parsed only to report a clear error, as extern variables are read-only
+    #[derive_where(skip)]
+    value: Option<Expression>,
+}
+
+impl ExternVariableDefinition {
+    pub(crate) fn parse<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        context.define_scope(|context, id| {
+            let pub_keyword_span = Span::parse_symbol(context, PUB_KEYWORD).ok();
+            Span::parse_symbol(context, EXTERN_KEYWORD)?;
+            Span::parse_symbol(context, VAR_KEYWORD)?;
+            let name_span = Span::parse_pattern(context, IDENTIFIER_PATTERN)?;
+            Span::parse_symbol(context, COLON_SYMBOL)?;
+            let type_ = TypeReference::parse(context)?;
+            let value = if Span::parse_symbol(context, EQUAL_SYMBOL).is_ok() {
+                Some(Expression::parse(context)?)
+            } else {
+                None
+            };
+            Span::parse_symbol(context, SEMICOLON_SYMBOL)?;
+            Ok(Self {
+                id,
+                scope: context.scope().to_vec(),
+                pub_keyword_span,
+                name: context.slice(name_span).into(),
+                name_span,
+                type_,
+                value,
+            })
+        })
+    }
+
+    pub(crate) fn index_item<'index>(&'index self, indexes: &mut Indexes<'index>) {
+        indexes
+            .items
+            .register(&self.name, ItemRef::ExternVariable(self));
+    }
+
+    pub(crate) fn validate(
+        &self,
+        context: &mut ValidateContext<'_>,
+        indexes: &Indexes<'_>,
+    ) -> Result<(), ValidateError> {
+        let ref_ = ItemRef::ExternVariable(self);
+        validators::item::check_unique_definition(ref_, context, indexes)?;
+        validators::item::check_usage(ref_, context, indexes);
+        validators::identifier::check_char_count(self.name_span, context);
+        validators::identifier::check_snake_case(self.name_span, context);
+        self.type_.validate(context)?;
+        let variable =
+            validators::extern_var::check_found(self.extern_variable(), self.name_span, context)?;
+        validators::expression::check_type(
+            variable.type_(),
+            self.type_(),
+            self.type_.span,
+            context,
+        )?;
+        validators::extern_var::check_read_only(
+            self.value.as_ref().map(Expression::span),
+            self.name_span,
+            context,
+        )
+    }
+
+    pub(crate) fn type_(&self) -> Option<Type> {
+        self.type_.type_()
+    }
+
+    pub(crate) fn transpile_ref(&self, shader: &mut String) {
+        if let Some(variable) = self.extern_variable() {
+            variable.transpile(shader);
+        }
+    }
+
+    fn extern_variable(&self) -> Option<ExternVariable> {
+        ExternVariable::from_name(&self.name)
+    }
+}
@@ -0,0 +1,226 @@
+use crate::compiler::indexes::Indexes;
+use crate::compiler::types::Type;
+use crate::language::items::ItemRef;
+use crate::language::patterns::{IDENTIFIER_PATTERN, STRING_LITERAL_PATTERN};
+use crate::language::symbols::{
+    ARROW_SYMBOL, CLOSE_BRACE_SYMBOL, CLOSE_PARENTHESIS_SYMBOL, COLON_SYMBOL, COMMA_SYMBOL,
+    EQUAL_SYMBOL, EXTERN_KEYWORD, FN_KEYWORD, OPEN_BRACE_SYMBOL, OPEN_PARENTHESIS_SYMBOL,
+    PUB_KEYWORD, SEMICOLON_SYMBOL,
+};
+use crate::language::type_::TypeReference;
+use crate::utils::parsing::{ParseContext, ParseError, Span, SpanProperties};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::validators;
+use itertools::Itertools;
+use std::fmt::Write;
+
+#[derive(Debug)]
+#[derive_where::derive_where(PartialEq, Eq, Hash)]
+pub(crate) struct FunctionDefinition {
+    pub(crate) id: u64,
+    #[derive_where(skip)]
+    pub(crate) scope: Vec<u64>,
+    #[derive_where(skip)]
+    pub(crate) pub_keyword_span: Option<Span>,
+    #[derive_where(skip)]
+    pub(crate) name_span: Span,
+    #[derive_where(skip)]
+    pub(crate) name: String,
+    #[derive_where(skip)]
+    pub(crate) params: Vec<FunctionParam>,
+    #[derive_where(skip)]
+    return_type: TypeReference,
+    #[derive_where(skip)]
+    wgsl: WgslFunction,
+}
+
+impl FunctionDefinition {
+    pub(crate) fn parse<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        context.define_scope(|context, id| {
+            let pub_keyword_span = Span::parse_symbol(context, PUB_KEYWORD).ok();
+            Span::parse_symbol(context, EXTERN_KEYWORD)?;
+            Span::parse_symbol(context, FN_KEYWORD)?;
+            let name_span = Span::parse_pattern(context, IDENTIFIER_PATTERN)?;
+            Span::parse_symbol(context, OPEN_PARENTHESIS_SYMBOL)?;
+            let (params, param_error) = context.parse_many(
+                0,
+                FunctionParam::parse,
+                Some(|context| Span::parse_symbol(context, COMMA_SYMBOL).map(|_| ())),
+            )?;
+            Span::parse_symbol(context, CLOSE_PARENTHESIS_SYMBOL).map_err(|error| {
+                ParseError::merge(&param_error.into_iter().chain([error]).collect::<Vec<_>>())
+            })?;
+            Span::parse_symbol(context, ARROW_SYMBOL)?;
+            let return_type = TypeReference::parse(context)?;
+            let wgsl = WgslFunction::parse(context)?;
+            Ok(Self {
+                id,
+                scope: context.scope().to_vec(),
+                pub_keyword_span,
+                name: context.slice(name_span).into(),
+                name_span,
+                params,
+                return_type,
+                wgsl,
+            })
+        })
+    }
+
+    pub(crate) fn index_item<'index>(&'index self, indexes: &mut Indexes<'index>) {
+        indexes.items.register(&self.name, ItemRef::Function(self));
+    }
+
+    pub(crate) fn validate(
+        &self,
+        context: &mut ValidateContext<'_>,
+        indexes: &Indexes<'_>,
+    ) -> Result<(), ValidateError> {
+        let ref_ = ItemRef::Function(self);
+        validators::item::check_unique_definition(ref_, context, indexes)?;
+        validators::item::check_usage(ref_, context, indexes);
+        validators::identifier::check_char_count(self.name_span, context);
+        validators::identifier::check_snake_case(self.name_span, context);
+        let mut is_param_invalid = false;
+        for param in &self.params {
+            if param.validate(context).is_err() {
+                is_param_invalid = true;
+            }
+        }
+        self.return_type.validate(context)?;
+        if is_param_invalid {
+            return Err(ValidateError);
+        }
+        validators::extern_fn::check_wgsl(self, context)
+    }
+
+    pub(crate) fn return_type(&self) -> Option<Type> {
+        self.return_type.type_()
+    }
+
+    pub(crate) fn transpile_ref(&self, shader: &mut String) {
+        _ = write!(shader, "f{}", self.id);
+    }
+
+    // calls are wrapped in a WGSL function, so that naga doesn't evaluate them at compile time
+    // when arguments are constant
+    pub(crate) fn transpile_definition(&self, shader: &mut String) {
+        self.transpile_wrapper(shader, &format!("f{}", self.id));
+    }
+
+    pub(crate) fn transpile_wrapper(&self, shader: &mut String, wrapper_name: &str) {
+        _ = write!(shader, "fn {wrapper_name}(");
+        for (index, param) in self.params.iter().enumerate() {
+            if index > 0 {
+                *shader += ", ";
+            }
+            _ = write!(shader, "{}: {}", param.name, param.type_.name);
+        }
+        _ = write!(shader, ") -> {} {{ ", self.return_type.name);
+        match &self.wgsl {
+            WgslFunction::SameName => self.transpile_wrapped_call(shader, &self.name),
+            WgslFunction::Renamed(name) => self.transpile_wrapped_call(shader, name),
+            WgslFunction::Body(lines) => {
+                for line in lines {
+                    *shader += line;
+                    *shader += " ";
+                }
+            }
+        }
+        *shader += "} ";
+    }
+
+    fn transpile_wrapped_call(&self, shader: &mut String, wgsl_name: &str) {
+        _ = write!(
+            shader,
+            "return {wgsl_name}({}); ",
+            self.params.iter().map(|param| &param.name).join(", ")
+        );
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct FunctionParam {
+    pub(crate) name_span: Span,
+    name: String,
+    pub(crate) type_: TypeReference,
+}
+
+impl FunctionParam {
+    fn parse<'context>(context: &mut ParseContext<'context>) -> Result<Self, ParseError<'context>> {
+        let name_span = Span::parse_pattern(context, IDENTIFIER_PATTERN)?;
+        Span::parse_symbol(context, COLON_SYMBOL)?;
+        let type_ = TypeReference::parse(context)?;
+        Ok(Self {
+            name_span,
+            name: context.slice(name_span).into(),
+            type_,
+        })
+    }
+
+    fn validate(&self, context: &mut ValidateContext<'_>) -> Result<(), ValidateError> {
+        validators::identifier::check_char_count(self.name_span, context);
+        validators::identifier::check_snake_case(self.name_span, context);
+        self.type_.validate(context)
+    }
+}
+
+// the WGSL code run when the extern function is called
+#[derive(Debug)]
+enum WgslFunction {
+    // `extern fn name(...) -> type;` calls the WGSL function with the same name
+    SameName,
+    // `extern fn name(...) -> type = wgsl_name;` calls the WGSL function `wgsl_name`
+    Renamed(String),
+    // `extern fn name(...) -> type { "line" ... }` runs the WGSL lines as function body
+    Body(Vec<String>),
+}
+
+impl WgslFunction {
+    fn parse<'context>(context: &mut ParseContext<'context>) -> Result<Self, ParseError<'context>> {
+        context.parse_any(&[
+            |context| Self::parse_same_name(context),
+            |context| Self::parse_renamed(context),
+            |context| Self::parse_body(context),
+        ])
+    }
+
+    fn parse_same_name<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        Span::parse_symbol(context, SEMICOLON_SYMBOL)?;
+        Ok(Self::SameName)
+    }
+
+    fn parse_renamed<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        Span::parse_symbol(context, EQUAL_SYMBOL)?;
+        let name_span = Span::parse_pattern(context, IDENTIFIER_PATTERN)?;
+        Span::parse_symbol(context, SEMICOLON_SYMBOL)?;
+        Ok(Self::Renamed(context.slice(name_span).into()))
+    }
+
+    fn parse_body<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        Span::parse_symbol(context, OPEN_BRACE_SYMBOL)?;
+        let (line_spans, line_error) = context.parse_many(
+            0,
+            |context| Span::parse_pattern(context, STRING_LITERAL_PATTERN),
+            None,
+        )?;
+        Span::parse_symbol(context, CLOSE_BRACE_SYMBOL).map_err(|error| {
+            ParseError::merge(&line_error.into_iter().chain([error]).collect::<Vec<_>>())
+        })?;
+        let lines = line_spans
+            .into_iter()
+            .map(|span| {
+                let line = context.slice(span);
+                line[1..line.len() - 1].into()
+            })
+            .collect();
+        Ok(Self::Body(lines))
+    }
+}
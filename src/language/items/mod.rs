@@ -1,9 +1,16 @@
+pub(crate) mod assert;
 pub(crate) mod const_;
+pub(crate) mod debug_print;
+pub(crate) mod draw;
+pub(crate) mod extern_var;
+pub(crate) mod fn_;
 pub(crate) mod var;
 
 use crate::compiler::dependencies::Dependencies;
 use crate::compiler::indexes::Indexes;
 use crate::language::items::const_::ConstantDefinition;
+use crate::language::items::extern_var::ExternVariableDefinition;
+use crate::language::items::fn_::FunctionDefinition;
 use crate::language::items::var::VariableDefinition;
 use crate::utils::indexing::{ItemNodeRef, NodeRef};
 use crate::utils::parsing::Span;
@@ -12,6 +19,8 @@ use crate::utils::parsing::Span;
 pub(crate) enum ItemRef<'item> {
     Variable(&'item VariableDefinition),
     Constant(&'item ConstantDefinition),
+    ExternVariable(&'item ExternVariableDefinition),
+    Function(&'item FunctionDefinition),
 }
 
 impl NodeRef for ItemRef<'_> {
@@ -19,6 +28,8 @@ impl NodeRef for ItemRef<'_> {
         match self {
             ItemRef::Variable(node) => node.name_span.file_index,
             ItemRef::Constant(node) => node.name_span.file_index,
+            ItemRef::ExternVariable(node) => node.name_span.file_index,
+            ItemRef::Function(node) => node.name_span.file_index,
         }
     }
 
@@ -26,6 +37,8 @@ impl NodeRef for ItemRef<'_> {
         match self {
             ItemRef::Variable(node) => node.id,
             ItemRef::Constant(node) => node.id,
+            ItemRef::ExternVariable(node) => node.id,
+            ItemRef::Function(node) => node.id,
         }
     }
 
@@ -33,6 +46,8 @@ impl NodeRef for ItemRef<'_> {
         match self {
             ItemRef::Variable(node) => &node.scope,
             ItemRef::Constant(node) => &node.scope,
+            ItemRef::ExternVariable(node) => &node.scope,
+            ItemRef::Function(node) => &node.scope,
         }
     }
 }
@@ -42,6 +57,8 @@ impl ItemNodeRef for ItemRef<'_> {
         match self {
             ItemRef::Variable(node) => node.pub_keyword_span.is_some(),
             ItemRef::Constant(node) => node.pub_keyword_span.is_some(),
+            ItemRef::ExternVariable(node) => node.pub_keyword_span.is_some(),
+            ItemRef::Function(node) => node.pub_keyword_span.is_some(),
         }
     }
 }
@@ -51,6 +68,16 @@ impl ItemRef<'_> {
         match self {
             ItemRef::Variable(node) => node.name_span,
             ItemRef::Constant(node) => node.name_span,
+            ItemRef::ExternVariable(node) => node.name_span,
+            ItemRef::Function(node) => node.name_span,
+        }
+    }
+
+    pub(crate) fn kind_name(&self) -> &'static str {
+        match self {
+            ItemRef::Variable(_) | ItemRef::ExternVariable(_) => "variable",
+            ItemRef::Constant(_) => "constant",
+            ItemRef::Function(_) => "function",
         }
     }
 
@@ -62,6 +89,7 @@ impl ItemRef<'_> {
         match self {
             ItemRef::Variable(node) => node.dependencies(dependencies, indexes),
             ItemRef::Constant(node) => node.dependencies(dependencies, indexes),
+            ItemRef::ExternVariable(_) | ItemRef::Function(_) => Ok(dependencies),
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::compiler::dependencies::Dependencies;
 use crate::compiler::indexes::Indexes;
-use crate::compiler::transpilation::MAIN_BUFFER_NAME;
+use crate::compiler::transpilation;
+use crate::compiler::types::Type;
 use crate::language::expressions::Expression;
 use crate::language::items::ItemRef;
 use crate::language::patterns::IDENTIFIER_PATTERN;
@@ -80,19 +81,35 @@ impl VariableDefinition {
         Ok(())
     }
 
-    pub(crate) fn transpile_buffer_field(&self, shader: &mut String) {
-        _ = write!(shader, "v{}: i32, ", self.id);
+    pub(crate) fn type_(&self, indexes: &Indexes<'_>) -> Option<Type> {
+        let dependencies = self.dependencies(Dependencies::new(ItemRef::Variable(self)), indexes);
+        if dependencies.is_ok() {
+            self.default_value.type_(indexes)
+        } else {
+            None // type cannot be determined if there are circular dependencies
+        }
+    }
+
+    pub(crate) fn transpile_buffer_field(&self, shader: &mut String, indexes: &Indexes<'_>) {
+        if let Some(type_) = self.type_(indexes) {
+            _ = write!(shader, "v{}: {type_}, ", self.id);
+        }
     }
 
     pub(crate) fn transpile_buffer_init(&self, shader: &mut String, indexes: &Indexes<'_>) {
-        self.transpile_ref(shader);
+        self.transpile_ref(shader, indexes);
         *shader += " = ";
         self.default_value.transpile(shader, indexes);
         *shader += "; ";
     }
 
-    pub(crate) fn transpile_ref(&self, shader: &mut String) {
-        *shader += MAIN_BUFFER_NAME;
+    pub(crate) fn transpile_ref(&self, shader: &mut String, indexes: &Indexes<'_>) {
+        let part_index = indexes
+            .variable_buffer_parts
+            .get(&self.id)
+            .copied()
+            .unwrap_or_default();
+        *shader += &transpilation::buffer_part_name(part_index);
         _ = write!(shader, ".v{}", self.id);
     }
 }
@@ -4,3 +4,4 @@ pub(crate) mod items;
 pub(crate) mod module;
 pub(crate) mod patterns;
 pub(crate) mod symbols;
+pub(crate) mod type_;
@@ -1,6 +1,11 @@
 use crate::compiler::indexes::Indexes;
 use crate::language::import::Import;
+use crate::language::items::assert::AssertDefinition;
 use crate::language::items::const_::ConstantDefinition;
+use crate::language::items::debug_print::DebugPrintDefinition;
+use crate::language::items::draw::DrawDefinition;
+use crate::language::items::extern_var::ExternVariableDefinition;
+use crate::language::items::fn_::FunctionDefinition;
 use crate::language::items::var::VariableDefinition;
 use crate::utils::parsing::{ParseContext, ParseError};
 use crate::utils::validation::{ValidateContext, ValidateError};
@@ -65,6 +70,46 @@ impl Module {
             }
         })
     }
+
+    pub(crate) fn functions(&self) -> impl Iterator<Item = &FunctionDefinition> {
+        self.items.iter().filter_map(|item| {
+            if let Item::Function(function) = item {
+                Some(function)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub(crate) fn asserts(&self) -> impl Iterator<Item = &AssertDefinition> {
+        self.items.iter().filter_map(|item| {
+            if let Item::Assert(assert) = item {
+                Some(assert)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub(crate) fn debug_prints(&self) -> impl Iterator<Item = &DebugPrintDefinition> {
+        self.items.iter().filter_map(|item| {
+            if let Item::DebugPrint(debug_print) = item {
+                Some(debug_print)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub(crate) fn draws(&self) -> impl Iterator<Item = &DrawDefinition> {
+        self.items.iter().filter_map(|item| {
+            if let Item::Draw(draw) = item {
+                Some(draw)
+            } else {
+                None
+            }
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -72,6 +117,11 @@ pub(crate) enum Item {
     Import(Import),
     Variable(VariableDefinition),
     Constant(ConstantDefinition),
+    ExternVariable(ExternVariableDefinition),
+    Function(FunctionDefinition),
+    Draw(DrawDefinition),
+    Assert(AssertDefinition),
+    DebugPrint(DebugPrintDefinition),
 }
 
 impl Item {
@@ -82,6 +132,11 @@ impl Item {
             |context| Import::parse(context).map(Self::Import),
             |context| VariableDefinition::parse(context).map(Self::Variable),
             |context| ConstantDefinition::parse(context).map(Self::Constant),
+            |context| ExternVariableDefinition::parse(context).map(Self::ExternVariable),
+            |context| FunctionDefinition::parse(context).map(Self::Function),
+            |context| DrawDefinition::parse(context).map(Self::Draw),
+            |context| AssertDefinition::parse(context).map(Self::Assert),
+            |context| DebugPrintDefinition::parse(context).map(Self::DebugPrint),
         ])
     }
 
@@ -90,14 +145,20 @@ impl Item {
             Self::Import(item) => item.index(indexes),
             Self::Variable(item) => item.index_item(indexes),
             Self::Constant(item) => item.index_item(indexes),
+            Self::ExternVariable(item) => item.index_item(indexes),
+            Self::Function(item) => item.index_item(indexes),
+            Self::Draw(_) | Self::Assert(_) | Self::DebugPrint(_) => (),
         }
     }
 
     pub(crate) fn index_refs(&self, indexes: &mut Indexes<'_>) {
         match self {
-            Self::Import(_) => (),
+            Self::Import(_) | Self::ExternVariable(_) | Self::Function(_) => (),
             Self::Variable(item) => item.index_refs(indexes),
             Self::Constant(item) => item.index_refs(indexes),
+            Self::Draw(item) => item.index_refs(indexes),
+            Self::Assert(item) => item.index_refs(indexes),
+            Self::DebugPrint(item) => item.index_refs(indexes),
         }
     }
 
@@ -110,6 +171,11 @@ impl Item {
             Self::Import(_) => Ok(()), // validated during previous pass
             Self::Variable(item) => item.validate(context, indexes),
             Self::Constant(item) => item.validate(context, indexes),
+            Self::ExternVariable(item) => item.validate(context, indexes),
+            Self::Function(item) => item.validate(context, indexes),
+            Self::Draw(item) => item.validate(context, indexes),
+            Self::Assert(item) => item.validate(context, indexes),
+            Self::DebugPrint(item) => item.validate(context, indexes),
         }
     }
 }
@@ -39,3 +39,25 @@ pub(crate) const I32_LITERAL_PATTERN: Pattern = Pattern {
         },
     ],
 };
+
+pub(crate) const STRING_LITERAL_PATTERN: Pattern = Pattern {
+    name: "string literal",
+    excluded_tokens: &[],
+    parts: &[
+        PatternPart {
+            is_valid_char: |char| char == '"',
+            min_count: 1,
+            max_count: 1,
+        },
+        PatternPart {
+            is_valid_char: |char| char != '"' && char != '\n',
+            min_count: 0,
+            max_count: usize::MAX,
+        },
+        PatternPart {
+            is_valid_char: |char| char == '"',
+            min_count: 1,
+            max_count: 1,
+        },
+    ],
+};
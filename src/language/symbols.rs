@@ -1,16 +1,41 @@
 use crate::utils::parsing::Symbol;
 
 pub(crate) const KEYWORDS: &[&str] = &[
+    ASSERT_KEYWORD.slice,
     CONST_KEYWORD.slice,
+    DEBUG_PRINT_KEYWORD.slice,
+    DRAW_KEYWORD.slice,
+    EXTERN_KEYWORD.slice,
+    FN_KEYWORD.slice,
     IMPORT_KEYWORD.slice,
     PUB_KEYWORD.slice,
     VAR_KEYWORD.slice,
 ];
 
+pub(crate) const ASSERT_KEYWORD: Symbol = Symbol {
+    name: "`assert`",
+    slice: "assert",
+};
 pub(crate) const CONST_KEYWORD: Symbol = Symbol {
     name: "`const`",
     slice: "const",
 };
+pub(crate) const DEBUG_PRINT_KEYWORD: Symbol = Symbol {
+    name: "`debug_print`",
+    slice: "debug_print",
+};
+pub(crate) const DRAW_KEYWORD: Symbol = Symbol {
+    name: "`draw`",
+    slice: "draw",
+};
+pub(crate) const EXTERN_KEYWORD: Symbol = Symbol {
+    name: "`extern`",
+    slice: "extern",
+};
+pub(crate) const FN_KEYWORD: Symbol = Symbol {
+    name: "`fn`",
+    slice: "fn",
+};
 pub(crate) const IMPORT_KEYWORD: Symbol = Symbol {
     name: "`import`",
     slice: "import",
@@ -24,6 +49,31 @@ pub(crate) const VAR_KEYWORD: Symbol = Symbol {
     slice: "var",
 };
 
+pub(crate) const RECTANGLE_SHAPE: Symbol = Symbol {
+    name: "`rectangle`",
+    slice: "rectangle",
+};
+
+pub(crate) const ARROW_SYMBOL: Symbol = Symbol {
+    name: "`->`",
+    slice: "->",
+};
+pub(crate) const CLOSE_BRACE_SYMBOL: Symbol = Symbol {
+    name: "`}`",
+    slice: "}",
+};
+pub(crate) const CLOSE_PARENTHESIS_SYMBOL: Symbol = Symbol {
+    name: "`)`",
+    slice: ")",
+};
+pub(crate) const COLON_SYMBOL: Symbol = Symbol {
+    name: "`:`",
+    slice: ":",
+};
+pub(crate) const COMMA_SYMBOL: Symbol = Symbol {
+    name: "`,`",
+    slice: ",",
+};
 pub(crate) const DOT_SYMBOL: Symbol = Symbol {
     name: "`.`",
     slice: ".",
@@ -32,6 +82,14 @@ pub(crate) const EQUAL_SYMBOL: Symbol = Symbol {
     name: "`=`",
     slice: "=",
 };
+pub(crate) const OPEN_BRACE_SYMBOL: Symbol = Symbol {
+    name: "`{`",
+    slice: "{",
+};
+pub(crate) const OPEN_PARENTHESIS_SYMBOL: Symbol = Symbol {
+    name: "`(`",
+    slice: "(",
+};
 pub(crate) const SEMICOLON_SYMBOL: Symbol = Symbol {
     name: "`;`",
     slice: ";",
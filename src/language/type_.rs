@@ -0,0 +1,31 @@
+use crate::compiler::types::Type;
+use crate::language::patterns::IDENTIFIER_PATTERN;
+use crate::utils::parsing::{ParseContext, ParseError, Span, SpanProperties};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::validators;
+
+#[derive(Debug)]
+pub(crate) struct TypeReference {
+    pub(crate) span: Span,
+    pub(crate) name: String,
+}
+
+impl TypeReference {
+    pub(crate) fn parse<'context>(
+        context: &mut ParseContext<'context>,
+    ) -> Result<Self, ParseError<'context>> {
+        let span = Span::parse_pattern(context, IDENTIFIER_PATTERN)?;
+        Ok(Self {
+            span,
+            name: context.slice(span).into(),
+        })
+    }
+
+    pub(crate) fn validate(&self, context: &mut ValidateContext<'_>) -> Result<(), ValidateError> {
+        validators::type_::check_found(self.type_(), self.span, context)
+    }
+
+    pub(crate) fn type_(&self) -> Option<Type> {
+        Type::from_name(&self.name)
+    }
+}
@@ -6,13 +6,43 @@ mod runner;
 mod utils;
 mod validators;
 
+pub use compiler::CompileOptions;
 pub use compiler::compile;
+pub use compiler::compile_with_options;
+pub use compiler::debug::Assertion;
+pub use compiler::debug::DebugPrint;
+pub use compiler::emission::ShaderFormat;
+pub use compiler::emit_shaders;
+pub use compiler::layout::TargetLimits;
 pub use compiler::save_compiled;
+pub use compiler::serialization::CompiledFormat;
+pub use compiler::source_map::SourceMap;
+pub use compiler::source_map::SourceMapping;
 pub use compiler::transpilation::Buffer;
 pub use compiler::transpilation::BufferField;
+pub use compiler::transpilation::BufferPart;
 pub use compiler::transpilation::Program;
+pub use compiler::types::Type;
+pub use runner::AdapterOptions;
+pub use runner::Backend;
+pub use runner::BufferKind;
 pub use runner::GpuValue;
+pub use runner::Input;
+pub use runner::InputScript;
+pub use runner::Interpreter;
+pub use runner::PendingSnapshot;
+pub use runner::Profile;
+pub use runner::ProfileSample;
+pub use runner::ProgramRunner;
 pub use runner::Runner;
+pub use runner::ShaderTimings;
+pub use runner::TestReport;
+pub use runner::TestResult;
+pub use runner::TestSuite;
+pub use runner::WgpuBackend;
+pub use runner::WgpuComputeShader;
+pub use runner::WgpuDrawShader;
+pub use runner::available_adapters;
 pub use runner::load_compiled;
 pub use utils::logs::Log;
 pub use utils::logs::LogInner;
@@ -3,14 +3,28 @@
 
 // coverage: off (difficult to test)
 
-use clap::Parser;
-use gpex::{Log, Program, Runner};
+use clap::{Parser, ValueEnum};
+use gpex::{
+    AdapterOptions, CompileOptions, CompiledFormat, InputScript, Interpreter, Log, Program,
+    ProgramRunner, Runner, ShaderFormat, TargetLimits, TestSuite,
+};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use wgpu::{Backends, DeviceType, PowerPreference};
+use winit::application::ApplicationHandler;
+use winit::dpi::PhysicalSize;
+use winit::event::{MouseButton, WindowEvent};
+use winit::event_loop::{ActiveEventLoop, EventLoop};
+use winit::keyboard::{KeyCode, PhysicalKey};
+use winit::window::{Window, WindowId};
 
 #[derive(Debug, Parser)]
 enum Args {
     Compile(CompileArgs),
     Run(RunArgs),
+    Test(TestArgs),
+    Info(InfoArgs),
 }
 
 #[derive(Debug, Parser)]
@@ -22,6 +36,54 @@ struct CompileArgs {
     /// Exit with code 1 in case there are warnings.
     #[arg(long, default_value_t = false)]
     is_warning_treated_as_error: bool,
+    /// Format of the compiled file.
+    #[arg(long, value_enum, default_value_t = FormatArg::Json)]
+    format: FormatArg,
+    /// Compress the compiled file (only with `--format binary`).
+    #[arg(long = "compress", default_value_t = false)]
+    is_compressed: bool,
+    /// Format generated shaders with indentation and comments referring to the source code.
+    #[arg(long = "pretty", default_value_t = false)]
+    is_pretty: bool,
+    /// Shader formats to emit in addition to the compiled file, separated by commas.
+    #[arg(long, value_enum, value_delimiter = ',')]
+    emit: Vec<EmitArg>,
+    /// Folder where emitted shaders are written (default: folder of the compiled file).
+    #[arg(long)]
+    out_dir: Option<PathBuf>,
+    /// Limits of the graphic devices targeted by the compiled program.
+    #[arg(long, value_enum, default_value_t = LimitsArg::Default)]
+    limits: LimitsArg,
+    /// Number of 32-bit words of the buffer storing debug prints run at initialization.
+    #[arg(
+        long,
+        default_value_t = CompileOptions::DEFAULT_DEBUG_PRINT_CAPACITY,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    debug_print_capacity: u32,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum LimitsArg {
+    /// Limits supported by most modern devices.
+    Default,
+    /// Limits supported by most devices, including older ones.
+    Downlevel,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum FormatArg {
+    Json,
+    Binary,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum EmitArg {
+    Wgsl,
+    Spirv,
+    Msl,
+    Hlsl,
+    Glsl,
 }
 
 #[derive(Debug, Parser)]
@@ -31,6 +93,121 @@ struct RunArgs {
     /// List of variables to display at each step in the terminal, in the format `<module dot path>:<variable name>`.
     #[arg(short='v', long="var", num_args(0..), default_values_t = Vec::<String>::new())]
     pub variable_paths: Vec<String>,
+    /// Run the program in a window until it is closed, instead of running a single step.
+    #[arg(long = "window", default_value_t = false)]
+    is_window_displayed: bool,
+    /// Number of steps to run before displaying variables and rendering (ignored with `--window`).
+    #[arg(long, default_value_t = 1)]
+    steps: u32,
+    /// Size of the rendering target in pixels, in the format `<width>x<height>`.
+    #[arg(long, default_value = "800x600", value_parser = parse_size)]
+    size: (u32, u32),
+    /// Path to a PNG image where the rendered frame is saved after the last step (ignored with `--window`).
+    #[arg(long)]
+    render_to: Option<PathBuf>,
+    /// Path to an input script file, where each line has the format `<step> <input name> <value>`.
+    #[arg(long)]
+    input_script: Option<PathBuf>,
+    /// Fixed time step in seconds, used instead of the system clock to make runs reproducible.
+    #[arg(long, value_parser = parse_timestep)]
+    fixed_timestep: Option<Duration>,
+    /// Path to a state file where all variable values are saved after the last step.
+    #[arg(long)]
+    save_state: Option<PathBuf>,
+    /// Path to a state file from which variable values are restored instead of being initialized
+    /// (assertions and debug prints are then not run).
+    #[arg(long)]
+    load_state: Option<PathBuf>,
+    /// Path to the source folder of the compiled program, to warn in case the program is outdated
+    /// (ignored when running a source folder).
+    #[arg(long)]
+    source: Option<PathBuf>,
+    /// Run the program on the CPU, without graphic adapter (draw items are not rendered).
+    #[arg(long = "cpu", default_value_t = false, conflicts_with_all = ["is_window_displayed", "render_to"])]
+    is_cpu_used: bool,
+    /// Limits of the graphic devices targeted by the program (only when running a source folder).
+    #[arg(long, value_enum, default_value_t = LimitsArg::Default)]
+    limits: LimitsArg,
+    /// Number of 32-bit words of the buffer storing debug prints run at initialization (only when running a source folder).
+    #[arg(
+        long,
+        default_value_t = CompileOptions::DEFAULT_DEBUG_PRINT_CAPACITY,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    debug_print_capacity: u32,
+    /// Measure the GPU duration of each shader run and display timings after the last step.
+    #[arg(
+        long = "profile",
+        default_value_t = false,
+        conflicts_with = "is_cpu_used"
+    )]
+    is_profiled: bool,
+    /// Path to a JSON file where measured GPU timings are saved in Chrome trace format (requires `--profile`).
+    #[arg(long, requires = "is_profiled")]
+    profile_trace: Option<PathBuf>,
+    #[command(flatten)]
+    adapter: AdapterArgs,
+}
+
+#[derive(Debug, Parser)]
+struct TestArgs {
+    /// Path to the source folder to test.
+    ///
+    /// Tests are global variables followed on the same line by an `// expected: <value>` comment,
+    /// and `assert` items, which are only checked once global variables are initialized.
+    input: PathBuf,
+    /// Number of steps to run before checking expected values.
+    #[arg(long, default_value_t = 1)]
+    steps: u32,
+    /// Fixed time step in seconds, used instead of the system clock to make runs reproducible.
+    #[arg(long, value_parser = parse_timestep)]
+    fixed_timestep: Option<Duration>,
+    /// Display only failed tests.
+    #[arg(long = "failures-only", default_value_t = false)]
+    is_failures_only: bool,
+    /// Run the tests on the CPU, without graphic adapter.
+    #[arg(long = "cpu", default_value_t = false)]
+    is_cpu_used: bool,
+    #[command(flatten)]
+    adapter: AdapterArgs,
+}
+
+#[derive(Debug, Parser)]
+struct AdapterArgs {
+    /// Graphics APIs that can be used, separated by commas (default: `WGPU_BACKEND` environment variable, or all).
+    #[arg(long, value_enum, value_delimiter = ',')]
+    backend: Vec<BackendArg>,
+    /// Part of the name of the graphic adapter to use, case-insensitive (see `gpex info`).
+    #[arg(long)]
+    adapter: Option<String>,
+    /// Power preference of the graphic adapter.
+    #[arg(long, value_enum)]
+    power: Option<PowerArg>,
+    /// Use a fallback graphic adapter (e.g. software renderer).
+    #[arg(long = "fallback-adapter", default_value_t = false)]
+    is_fallback_forced: bool,
+}
+
+#[derive(Debug, Parser)]
+struct InfoArgs {
+    /// Graphics APIs to list adapters for, separated by commas (default: `WGPU_BACKEND` environment variable, or all).
+    #[arg(long, value_enum, value_delimiter = ',')]
+    backend: Vec<BackendArg>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum BackendArg {
+    Vulkan,
+    Metal,
+    Dx12,
+    #[value(name = "gl")]
+    OpenGl,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum PowerArg {
+    Low,
+    High,
 }
 
 #[tokio::main]
@@ -38,25 +215,88 @@ async fn main() {
     match Args::parse() {
         Args::Compile(args) => compile(&args),
         Args::Run(args) => run(&args).await,
+        Args::Test(args) => test(&args).await,
+        Args::Info(args) => info(&args).await,
     }
 }
 
 fn compile(args: &CompileArgs) {
-    let program = compile_folder(&args.input, args.is_warning_treated_as_error);
-    if let Err(errors) = gpex::save_compiled(&program, &args.output) {
+    let mut program = compile_folder(
+        &args.input,
+        args.is_warning_treated_as_error,
+        args.limits,
+        args.debug_print_capacity,
+    );
+    if args.is_pretty {
+        program = program.into_pretty();
+    }
+    let format = match (args.format, args.is_compressed) {
+        (FormatArg::Json, _) => CompiledFormat::Json,
+        (FormatArg::Binary, false) => CompiledFormat::Binary,
+        (FormatArg::Binary, true) => CompiledFormat::CompressedBinary,
+    };
+    if let Err(errors) = gpex::save_compiled(&program, &args.output, format) {
         display_log(&errors);
         std::process::exit(1);
     } else {
         println!("info: program saved in \"{}\"", args.output.display());
     }
+    if !args.emit.is_empty() {
+        emit_shaders(&program, args);
+    }
+}
+
+fn emit_shaders(program: &Program, args: &CompileArgs) {
+    let formats: Vec<_> = args
+        .emit
+        .iter()
+        .map(|format| match format {
+            EmitArg::Wgsl => ShaderFormat::Wgsl,
+            EmitArg::Spirv => ShaderFormat::SpirV,
+            EmitArg::Msl => ShaderFormat::Msl,
+            EmitArg::Hlsl => ShaderFormat::Hlsl,
+            EmitArg::Glsl => ShaderFormat::Glsl,
+        })
+        .collect();
+    let folder = args.out_dir.clone().unwrap_or_else(|| {
+        (args.output.parent())
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    });
+    match gpex::emit_shaders(program, &formats, &folder) {
+        Ok(paths) => {
+            for path in paths {
+                println!("info: shader saved in \"{}\"", path.display());
+            }
+        }
+        Err(errors) => {
+            display_log(&errors);
+            std::process::exit(1);
+        }
+    }
 }
 
 async fn run(args: &RunArgs) {
     if args.input.is_dir() {
-        run_program(compile_folder(&args.input, false), args).await;
+        run_program(
+            compile_folder(&args.input, false, args.limits, args.debug_print_capacity),
+            args,
+        )
+        .await;
     } else {
         match gpex::load_compiled(&args.input) {
-            Ok(program) => run_program(program, args).await,
+            Ok(program) => {
+                if let Some(source) = &args.source
+                    && !unwrap_or_exit(program.is_compiled_from(source))
+                {
+                    println!(
+                        "warning: compiled program \"{}\" is outdated, recompile \"{}\"",
+                        args.input.display(),
+                        source.display()
+                    );
+                }
+                run_program(program, args).await;
+            }
             Err(errors) => {
                 display_log(&errors);
                 std::process::exit(1);
@@ -65,8 +305,128 @@ async fn run(args: &RunArgs) {
     }
 }
 
-fn compile_folder(folder_path: &Path, is_warning_treated_as_error: bool) -> Program {
-    match gpex::compile(folder_path, is_warning_treated_as_error) {
+async fn test(args: &TestArgs) {
+    let (suite, warnings) = unwrap_or_exit(TestSuite::load(&args.input, CompileOptions::default()));
+    display_log(&warnings);
+    if suite.is_empty() && suite.program().asserts.is_empty() {
+        println!("warning: no test found in \"{}\"", args.input.display());
+        return;
+    }
+    let program = suite.program().clone();
+    let report = if args.is_cpu_used {
+        let mut interpreter = unwrap_or_exit(Interpreter::new(program));
+        interpreter.set_fixed_timestep(args.fixed_timestep);
+        suite.run(&mut interpreter, args.steps)
+    } else {
+        let options = adapter_options(&args.adapter);
+        let mut runner = unwrap_or_exit(Runner::with_adapter(program, &options).await);
+        runner.set_fixed_timestep(args.fixed_timestep);
+        suite.run(&mut runner, args.steps)
+    };
+    display_log(&report.debug_logs);
+    for result in &report.results {
+        if !args.is_failures_only || !result.is_success() {
+            print!("{}", result.to_log());
+        }
+    }
+    display_log(&report.errors);
+    let failure_count = report.failure_count();
+    println!(
+        "info: {} passed, {failure_count} failed",
+        report.results.len() - failure_count
+    );
+    if !report.is_success() {
+        std::process::exit(1);
+    }
+}
+
+async fn info(args: &InfoArgs) {
+    let mut options = AdapterOptions::default();
+    options.backends = backends(&args.backend);
+    let adapters = gpex::available_adapters(&options).await;
+    if adapters.is_empty() {
+        println!("warning: no graphic adapter found");
+    }
+    for (index, adapter) in adapters.iter().enumerate() {
+        let info = adapter.get_info();
+        println!("adapter #{index}: {}", info.name);
+        println!("  backend: {}", info.backend);
+        println!("  device type: {}", device_type_name(info.device_type));
+        let driver = [info.driver, info.driver_info]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>();
+        println!("  driver: {}", driver.join(" "));
+        println!("  features:");
+        for (name, _) in adapter.features().iter_names() {
+            println!("    {name}");
+        }
+        println!("  limits:");
+        // each line of the debug format is a `<name>: <value>,` field, except the braces
+        for line in format!("{:#?}", adapter.limits()).lines() {
+            if let Some(field) = line.trim().strip_suffix(',') {
+                println!("    {field}");
+            }
+        }
+    }
+}
+
+fn device_type_name(device_type: DeviceType) -> &'static str {
+    match device_type {
+        DeviceType::Other => "other",
+        DeviceType::IntegratedGpu => "integrated GPU",
+        DeviceType::DiscreteGpu => "discrete GPU",
+        DeviceType::VirtualGpu => "virtual GPU",
+        DeviceType::Cpu => "CPU",
+    }
+}
+
+fn adapter_options(args: &AdapterArgs) -> AdapterOptions {
+    let mut options = AdapterOptions::default();
+    options.backends = backends(&args.backend);
+    options.name.clone_from(&args.adapter);
+    options.power_preference = match args.power {
+        Some(PowerArg::Low) => PowerPreference::LowPower,
+        Some(PowerArg::High) => PowerPreference::HighPerformance,
+        None => PowerPreference::None,
+    };
+    options.is_fallback_forced = args.is_fallback_forced;
+    options
+}
+
+fn run_adapter_options(args: &RunArgs) -> AdapterOptions {
+    let mut options = adapter_options(&args.adapter);
+    options.is_profiling_enabled = args.is_profiled;
+    options
+}
+
+fn backends(args: &[BackendArg]) -> Option<Backends> {
+    (!args.is_empty()).then(|| {
+        args.iter()
+            .map(|backend| match backend {
+                BackendArg::Vulkan => Backends::VULKAN,
+                BackendArg::Metal => Backends::METAL,
+                BackendArg::Dx12 => Backends::DX12,
+                BackendArg::OpenGl => Backends::GL,
+            })
+            .fold(Backends::empty(), |all, backend| all | backend)
+    })
+}
+
+fn compile_folder(
+    folder_path: &Path,
+    is_warning_treated_as_error: bool,
+    limits: LimitsArg,
+    debug_print_capacity: u32,
+) -> Program {
+    let mut options = CompileOptions::default();
+    options.is_warning_treated_as_error = is_warning_treated_as_error;
+    options.limits = match limits {
+        LimitsArg::Default => TargetLimits::default(),
+        LimitsArg::Downlevel => TargetLimits::downlevel(),
+    };
+    options.debug_print_capacity = debug_print_capacity;
+    match gpex::compile_with_options(folder_path, options) {
         Ok((program, logs)) => {
             display_log(&logs);
             program
@@ -79,16 +439,82 @@ fn compile_folder(folder_path: &Path, is_warning_treated_as_error: bool) -> Prog
 }
 
 async fn run_program(program: Program, args: &RunArgs) {
-    let mut runner = match Runner::new(program).await {
-        Ok(runner) => runner,
-        Err(errors) => {
-            display_log(&errors);
-            std::process::exit(1);
-        }
+    let input_script = args
+        .input_script
+        .as_ref()
+        .map(|path| unwrap_or_exit(InputScript::load(path)))
+        .unwrap_or_default();
+    if args.is_window_displayed {
+        run_window(program, input_script, args);
+        return;
+    }
+    if args.is_cpu_used {
+        let mut interpreter = unwrap_or_exit(Interpreter::new(program));
+        run_steps(&mut interpreter, &input_script, args);
+        return;
+    }
+    let mut runner =
+        unwrap_or_exit(Runner::with_adapter(program, &run_adapter_options(args)).await);
+    runner.resize(args.size.0, args.size.1);
+    run_steps(&mut runner, &input_script, args);
+    display_profile(&runner, args);
+    if let Some(path) = &args.render_to {
+        unwrap_or_exit(runner.save_frame(path));
+        println!("info: frame saved in \"{}\"", path.display());
+    }
+}
+
+fn run_steps(runner: &mut impl ProgramRunner, input_script: &InputScript, args: &RunArgs) {
+    runner.set_fixed_timestep(args.fixed_timestep);
+    if let Some(path) = &args.load_state {
+        display_log(&unwrap_or_exit(runner.load_state(path)));
+    }
+    for step in 0..args.steps {
+        input_script.apply(step, runner.input_mut());
+        let result = runner.run_step();
+        display_log(&runner.take_debug_logs());
+        unwrap_or_exit(result);
+    }
+    display_variables(runner, args);
+    save_state(runner, args);
+}
+
+fn run_window(program: Program, input_script: InputScript, args: &RunArgs) {
+    let event_loop = EventLoop::new().unwrap_or_else(|error| {
+        println!("error: cannot create window event loop: {error}");
+        std::process::exit(1);
+    });
+    let mut app = WindowApp {
+        program: Some(program),
+        size: args.size,
+        fixed_timestep: args.fixed_timestep,
+        load_state_path: args.load_state.clone(),
+        adapter_options: run_adapter_options(args),
+        window: None,
+        runner: None,
+        input_script,
+        step: 0,
     };
-    runner.run_step();
+    if let Err(error) = event_loop.run_app(&mut app) {
+        println!("error: window event loop failed: {error}");
+        std::process::exit(1);
+    }
+    if let Some(runner) = &app.runner {
+        display_variables(runner, args);
+        save_state(runner, args);
+        display_profile(runner, args);
+    }
+}
+
+fn display_variables(runner: &impl ProgramRunner, args: &RunArgs) {
+    let paths = args
+        .variable_paths
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    let values = runner.read_variables(&paths);
     for variable_path in &args.variable_paths {
-        if let Some(value) = runner.read_variable(variable_path) {
+        if let Some(value) = values.get(variable_path) {
             println!("info: {variable_path} = `{value}`");
         } else {
             println!("warning: `{variable_path}` variable not found");
@@ -96,8 +522,218 @@ async fn run_program(program: Program, args: &RunArgs) {
     }
 }
 
+fn save_state(runner: &impl ProgramRunner, args: &RunArgs) {
+    if let Some(path) = &args.save_state {
+        unwrap_or_exit(runner.save_state(path));
+        println!("info: state saved in \"{}\"", path.display());
+    }
+}
+
+fn display_profile(runner: &Runner, args: &RunArgs) {
+    if !args.is_profiled {
+        return;
+    }
+    let Some(profile) = runner.backend().profile() else {
+        println!(
+            "warning: graphic adapter doesn't support timestamp queries, profiling is disabled"
+        );
+        return;
+    };
+    println!("info: GPU timings in microseconds");
+    println!(
+        "  {:<16} {:>8} {:>12} {:>12} {:>12}",
+        "shader", "runs", "min", "avg", "max"
+    );
+    for timings in profile.shader_timings() {
+        println!(
+            "  {:<16} {:>8} {:>12.3} {:>12.3} {:>12.3}",
+            timings.shader,
+            timings.count,
+            timings.min.as_secs_f64() * 1e6,
+            timings.avg.as_secs_f64() * 1e6,
+            timings.max.as_secs_f64() * 1e6,
+        );
+    }
+    if let Some(path) = &args.profile_trace {
+        unwrap_or_exit(profile.save_chrome_trace(path));
+        println!("info: profile trace saved in \"{}\"", path.display());
+    }
+}
+
+fn unwrap_or_exit<T>(result: Result<T, Vec<Log>>) -> T {
+    result.unwrap_or_else(|errors| {
+        display_log(&errors);
+        std::process::exit(1);
+    })
+}
+
+fn parse_size(size: &str) -> Result<(u32, u32), String> {
+    size.split_once('x')
+        .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)))
+        .filter(|&(width, height)| width > 0 && height > 0)
+        .ok_or_else(|| format!("invalid size `{size}`, expected format is `<width>x<height>`"))
+}
+
+fn parse_timestep(timestep: &str) -> Result<Duration, String> {
+    timestep
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f32(seconds).ok())
+        .filter(|timestep| !timestep.is_zero())
+        .ok_or_else(|| format!("invalid time step `{timestep}`, expected a positive number"))
+}
+
 fn display_log(logs: &[Log]) {
     for log in logs {
         print!("{log}");
     }
 }
+
+struct WindowApp {
+    program: Option<Program>,
+    size: (u32, u32),
+    fixed_timestep: Option<Duration>,
+    load_state_path: Option<PathBuf>,
+    adapter_options: AdapterOptions,
+    window: Option<Arc<Window>>,
+    runner: Option<Runner>,
+    input_script: InputScript,
+    step: u32,
+}
+
+impl ApplicationHandler for WindowApp {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        let Some(program) = self.program.take() else {
+            return;
+        };
+        let attributes = Window::default_attributes()
+            .with_title("GPEx")
+            .with_inner_size(PhysicalSize::new(self.size.0, self.size.1));
+        let window = Arc::new(
+            event_loop
+                .create_window(attributes)
+                .unwrap_or_else(|error| {
+                    println!("error: cannot create window: {error}");
+                    std::process::exit(1);
+                }),
+        );
+        let size = window.inner_size();
+        let runner = tokio::task::block_in_place(|| {
+            tokio::runtime::Handle::current().block_on(Runner::with_window(
+                program,
+                window.clone(),
+                size.width,
+                size.height,
+                &self.adapter_options,
+            ))
+        });
+        let mut runner = unwrap_or_exit(runner);
+        runner.set_fixed_timestep(self.fixed_timestep);
+        if let Some(path) = &self.load_state_path {
+            display_log(&unwrap_or_exit(runner.load_state(path)));
+        }
+        self.runner = Some(runner);
+        window.request_redraw();
+        self.window = Some(window);
+    }
+
+    #[expect(clippy::wildcard_enum_match_arm)] // other events are ignored
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
+        let (Some(window), Some(runner)) = (&self.window, &mut self.runner) else {
+            return;
+        };
+        match event {
+            WindowEvent::Resized(size) => runner.resize(size.width, size.height),
+            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::RedrawRequested => {
+                self.input_script.apply(self.step, runner.input_mut());
+                let result = runner.run_step();
+                display_log(&runner.take_debug_logs());
+                unwrap_or_exit(result);
+                runner.render();
+                self.step += 1;
+                window.request_redraw();
+            }
+            WindowEvent::KeyboardInput { event, .. } => {
+                if let PhysicalKey::Code(code) = event.physical_key
+                    && let Some(name) = key_input_name(code)
+                {
+                    runner
+                        .input_mut()
+                        .set(name, event.state.is_pressed().into());
+                }
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                #[expect(clippy::cast_possible_truncation)] // pixel position
+                let (mouse_x, mouse_y) = (position.x as i32, position.y as i32);
+                runner.input_mut().set("mouse_x", mouse_x);
+                runner.input_mut().set("mouse_y", mouse_y);
+            }
+            WindowEvent::MouseInput { state, button, .. } => {
+                let name = match button {
+                    MouseButton::Left => "mouse_left",
+                    MouseButton::Right => "mouse_right",
+                    MouseButton::Middle => "mouse_middle",
+                    MouseButton::Back | MouseButton::Forward | MouseButton::Other(_) => return,
+                };
+                runner.input_mut().set(name, state.is_pressed().into());
+            }
+            _ => (),
+        }
+    }
+}
+
+#[expect(clippy::wildcard_enum_match_arm)] // other keys are not supported
+fn key_input_name(code: KeyCode) -> Option<&'static str> {
+    Some(match code {
+        KeyCode::KeyA => "key_a",
+        KeyCode::KeyB => "key_b",
+        KeyCode::KeyC => "key_c",
+        KeyCode::KeyD => "key_d",
+        KeyCode::KeyE => "key_e",
+        KeyCode::KeyF => "key_f",
+        KeyCode::KeyG => "key_g",
+        KeyCode::KeyH => "key_h",
+        KeyCode::KeyI => "key_i",
+        KeyCode::KeyJ => "key_j",
+        KeyCode::KeyK => "key_k",
+        KeyCode::KeyL => "key_l",
+        KeyCode::KeyM => "key_m",
+        KeyCode::KeyN => "key_n",
+        KeyCode::KeyO => "key_o",
+        KeyCode::KeyP => "key_p",
+        KeyCode::KeyQ => "key_q",
+        KeyCode::KeyR => "key_r",
+        KeyCode::KeyS => "key_s",
+        KeyCode::KeyT => "key_t",
+        KeyCode::KeyU => "key_u",
+        KeyCode::KeyV => "key_v",
+        KeyCode::KeyW => "key_w",
+        KeyCode::KeyX => "key_x",
+        KeyCode::KeyY => "key_y",
+        KeyCode::KeyZ => "key_z",
+        KeyCode::Digit0 => "key_0",
+        KeyCode::Digit1 => "key_1",
+        KeyCode::Digit2 => "key_2",
+        KeyCode::Digit3 => "key_3",
+        KeyCode::Digit4 => "key_4",
+        KeyCode::Digit5 => "key_5",
+        KeyCode::Digit6 => "key_6",
+        KeyCode::Digit7 => "key_7",
+        KeyCode::Digit8 => "key_8",
+        KeyCode::Digit9 => "key_9",
+        KeyCode::ArrowLeft => "key_left",
+        KeyCode::ArrowRight => "key_right",
+        KeyCode::ArrowUp => "key_up",
+        KeyCode::ArrowDown => "key_down",
+        KeyCode::Space => "key_space",
+        KeyCode::Enter => "key_enter",
+        KeyCode::Escape => "key_escape",
+        KeyCode::Tab => "key_tab",
+        KeyCode::Backspace => "key_backspace",
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => "key_shift",
+        KeyCode::ControlLeft | KeyCode::ControlRight => "key_control",
+        KeyCode::AltLeft | KeyCode::AltRight => "key_alt",
+        _ => return None,
+    })
+}
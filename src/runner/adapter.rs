@@ -0,0 +1,97 @@
+use crate::runner::utils;
+use crate::{Log, LogInner, LogLevel};
+use wgpu::{Adapter, Backends, Instance, PowerPreference, RequestAdapterOptions, Surface};
+
+/// Options used to select the graphic adapter running programs.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct AdapterOptions {
+    /// The graphics APIs that can be used.
+    ///
+    /// If `None`, the APIs are read from the `WGPU_BACKEND` environment variable, or all APIs are
+    /// used if the variable is not set.
+    pub backends: Option<Backends>,
+    /// A part of the name of the adapter to use, case-insensitive.
+    ///
+    /// If `None`, the adapter is selected by `wgpu` depending on the other options.
+    pub name: Option<String>,
+    /// The power preference of the adapter.
+    pub power_preference: PowerPreference,
+    /// Whether a fallback adapter (e.g. software renderer) must be used.
+    pub is_fallback_forced: bool,
+    /// Whether the GPU duration of each shader run is measured.
+    ///
+    /// Profiling is ignored if the adapter doesn't support timestamp queries.
+    /// Measured timings are available with [`WgpuBackend::profile`](crate::WgpuBackend::profile).
+    pub is_profiling_enabled: bool,
+}
+
+impl AdapterOptions {
+    pub(crate) fn backends(&self) -> Backends {
+        self.backends
+            .unwrap_or_else(|| Backends::from_env().unwrap_or_else(Backends::all))
+    }
+}
+
+/// Lists the graphic adapters available with the graphics APIs of `options`.
+///
+/// Other options are ignored.
+pub async fn available_adapters(options: &AdapterOptions) -> Vec<Adapter> {
+    let instance = utils::create_instance(options.backends());
+    instance.enumerate_adapters(options.backends()).await
+}
+
+pub(crate) async fn select_adapter(
+    instance: &Instance,
+    surface: Option<&Surface<'_>>,
+    options: &AdapterOptions,
+) -> Result<Adapter, Vec<Log>> {
+    if let Some(name) = &options.name {
+        let adapters = instance.enumerate_adapters(options.backends()).await;
+        let names: Vec<_> = adapters
+            .iter()
+            .map(|adapter| adapter.get_info().name)
+            .collect();
+        adapters
+            .into_iter()
+            .find(|adapter| {
+                adapter
+                    .get_info()
+                    .name
+                    .to_lowercase()
+                    .contains(&name.to_lowercase())
+                    && surface.is_none_or(|surface| adapter.is_surface_supported(surface))
+            })
+            .ok_or_else(|| {
+                vec![Log {
+                    level: LogLevel::Error,
+                    message: format!("no supported graphic adapter matching `{name}`"),
+                    location: None,
+                    inner: names
+                        .into_iter()
+                        .map(|name| LogInner {
+                            level: LogLevel::Info,
+                            message: format!("available adapter: `{name}`"),
+                            location: None,
+                        })
+                        .collect(),
+                }]
+            })
+    } else {
+        let request = RequestAdapterOptions {
+            power_preference: options.power_preference,
+            force_fallback_adapter: options.is_fallback_forced,
+            compatible_surface: surface,
+        };
+        instance.request_adapter(&request).await.map_err(|error| {
+            // coverage: off (difficult to test)
+            vec![Log {
+                level: LogLevel::Error,
+                message: format!("no supported graphic adapter found: {error}"),
+                location: None,
+                inner: vec![],
+            }]
+        })
+        // coverage: on
+    }
+}
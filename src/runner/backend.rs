@@ -0,0 +1,126 @@
+use crate::Log;
+use crate::compiler::source_map::SourceMap;
+use std::fmt::Debug;
+
+/// A backend executing the GPU operations needed by [`Runner`](crate::Runner).
+///
+/// [`WgpuBackend`](crate::WgpuBackend) runs the operations on a graphic adapter. Other backends
+/// can be implemented, for example to run programs without hardware or to record the operations
+/// requested by the runner.
+pub trait Backend {
+    /// A buffer stored by the backend.
+    type Buffer: Debug;
+    /// A compute shader ready to be dispatched.
+    type ComputeShader: Debug;
+    /// A draw shader ready to be rendered.
+    type DrawShader: Debug;
+
+    /// Creates a zero-initialized buffer of `size` bytes.
+    ///
+    /// `label` identifies the buffer in debugging tools.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the buffer cannot be created.
+    fn create_buffer(
+        &mut self,
+        label: &str,
+        kind: BufferKind,
+        size: u64,
+    ) -> Result<Self::Buffer, Vec<Log>>;
+
+    /// Writes `bytes` in a buffer, starting at byte `offset`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the buffer cannot be written.
+    fn write_buffer(
+        &mut self,
+        buffer: &Self::Buffer,
+        offset: u64,
+        bytes: &[u8],
+    ) -> Result<(), Vec<Log>>;
+
+    /// Reads `size` bytes of a buffer, starting at byte `offset`.
+    ///
+    /// All operations requested before are finished before the buffer is read.
+    fn read_buffer(&self, buffer: &Self::Buffer, offset: u64, size: u64) -> Vec<u8>;
+
+    /// Reads the first bytes of several buffers with a single operation.
+    ///
+    /// Each buffer is associated to the number of bytes to read, and the read bytes of all buffers
+    /// are concatenated in order.
+    /// All operations requested before are finished before the buffers are read.
+    fn read_buffers(&self, buffers: &[(&Self::Buffer, u64)]) -> Vec<u8>;
+
+    /// Creates a compute shader from WGSL `code`.
+    ///
+    /// `buffers` are bound in order to the first part of the main buffer, the input buffer, the
+    /// time buffer, the other parts of the main buffer and the debug buffer storing assertion
+    /// failures and debug print records, if the program has assertions or debug prints. The debug
+    /// buffer replaces the first part of the main buffer if the program has no variable.
+    /// `label` identifies the shader in error messages (e.g. `init shader`).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the shader cannot be created.
+    fn create_compute_shader(
+        &mut self,
+        label: &str,
+        code: &str,
+        source_map: &SourceMap,
+        buffers: &[&Self::Buffer],
+    ) -> Result<Self::ComputeShader, Vec<Log>>;
+
+    /// Runs a compute shader with a single workgroup.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the shader cannot be run.
+    fn dispatch(&mut self, shader: &Self::ComputeShader) -> Result<(), Vec<Log>>;
+
+    /// Creates a draw shader from WGSL `code`.
+    ///
+    /// `buffers` are bound in order to the target size buffer, the input buffer, the time buffer
+    /// and the parts of the main buffer, if the program has variables.
+    /// `label` identifies the shader in error messages (e.g. `draw shader #0`).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the shader cannot be created.
+    fn create_draw_shader(
+        &mut self,
+        label: &str,
+        code: &str,
+        source_map: &SourceMap,
+        buffers: &[&Self::Buffer],
+    ) -> Result<Self::DrawShader, Vec<Log>>;
+
+    /// Renders draw shaders on the rendering target, in order.
+    fn render(&mut self, shaders: &[Self::DrawShader]);
+
+    /// Returns the size in pixels of the rendering target.
+    fn target_size(&self) -> (u32, u32);
+
+    /// Resizes the rendering target.
+    ///
+    /// The size is clamped so that both dimensions are at least one pixel.
+    fn resize_target(&mut self, width: u32, height: u32);
+
+    /// Returns the last rendered frame as RGBA bytes.
+    ///
+    /// `None` is returned if the rendering target cannot be read.
+    fn read_target(&self) -> Option<Vec<u8>>;
+}
+
+/// The way a buffer is accessed by shaders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BufferKind {
+    /// Storage buffer written by compute shaders and read by draw shaders.
+    Storage,
+    /// Storage buffer only read by shaders.
+    ReadOnlyStorage,
+    /// Uniform buffer.
+    Uniform,
+}
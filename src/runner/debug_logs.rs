@@ -0,0 +1,90 @@
+use crate::compiler::debug::{Assertion, DebugPrint};
+use crate::language::items::debug_print::PLACEHOLDER;
+use crate::runner::GpuValue;
+use crate::runner::errors;
+use crate::{Log, LogInner, LogLevel};
+use itertools::Itertools;
+use std::path::Path;
+
+const ASSERT_KEYWORD_LEN: usize = "assert".len();
+const DEBUG_PRINT_KEYWORD_LEN: usize = "debug_print".len();
+
+pub(crate) fn assert_failure(assert: &Assertion) -> Log {
+    source_log(
+        LogLevel::Error,
+        format!("assertion failed: {}", assert.message),
+        (&assert.path, assert.line, assert.column),
+        ASSERT_KEYWORD_LEN,
+    )
+}
+
+// returns a log for each print record, where `words` contains the records in print order
+pub(crate) fn print_logs(prints: &[DebugPrint], mut words: &[u32]) -> Vec<Log> {
+    let mut logs = vec![];
+    while let Some((&print_id, remaining_words)) = words.split_first() {
+        let Some(print) = prints.get(print_id as usize) else {
+            break; // no-coverage (IDs are always valid)
+        };
+        let Some((arg_words, remaining_words)) =
+            remaining_words.split_at_checked(print.arg_types.len())
+        else {
+            break; // no-coverage (records are always complete)
+        };
+        let values = print
+            .arg_types
+            .iter()
+            .zip(arg_words)
+            .map(|(&type_, word)| GpuValue::from_bytes(type_, &word.to_ne_bytes()).to_string());
+        // the number of placeholders is checked during validation phase
+        let message: String = print
+            .format
+            .split(PLACEHOLDER)
+            .map(String::from)
+            .interleave(values)
+            .collect();
+        logs.push(source_log(
+            LogLevel::Info,
+            message,
+            (&print.path, print.line, print.column),
+            DEBUG_PRINT_KEYWORD_LEN,
+        ));
+        words = remaining_words;
+    }
+    logs
+}
+
+pub(crate) fn dropped_prints(dropped_count: u32, print_capacity: u32) -> Log {
+    Log {
+        level: LogLevel::Warning,
+        message: format!("{dropped_count} debug print(s) dropped because the debug buffer is full"),
+        location: None,
+        inner: vec![LogInner {
+            level: LogLevel::Info,
+            message: format!(
+                "debug buffer stores at most {print_capacity} words during initialization"
+            ),
+            location: None,
+        }],
+    }
+}
+
+// the location is added to the message if the GPEx source file cannot be read
+fn source_log(
+    level: LogLevel,
+    message: String,
+    (path, line, column): (&Path, usize, usize),
+    len: usize,
+) -> Log {
+    let location = errors::source_location(path, line, column, len);
+    let message = if location.is_some() {
+        message
+    } else {
+        format!("{message} (at {}:{line}:{column})", path.display())
+    };
+    Log {
+        level,
+        message,
+        location,
+        inner: vec![],
+    }
+}
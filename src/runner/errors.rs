@@ -0,0 +1,125 @@
+use crate::compiler::source_map::{self, SourceMap};
+use crate::{Log, LogInner, LogLevel, LogLocation};
+use std::fs;
+use std::path::Path;
+use wgpu::{CompilationMessageType, Device, ErrorFilter, ErrorScopeGuard, ShaderModule};
+
+// captures all errors generated by GPU operations until popped
+pub(crate) struct ErrorScope {
+    internal: ErrorScopeGuard,
+    out_of_memory: ErrorScopeGuard,
+    validation: ErrorScopeGuard,
+}
+
+impl ErrorScope {
+    pub(crate) fn push(device: &Device) -> Self {
+        Self {
+            internal: device.push_error_scope(ErrorFilter::Internal),
+            out_of_memory: device.push_error_scope(ErrorFilter::OutOfMemory),
+            validation: device.push_error_scope(ErrorFilter::Validation),
+        }
+    }
+
+    pub(crate) fn pop(self, operation: &str) -> Result<(), Vec<Log>> {
+        let validation = self.validation.pop();
+        let out_of_memory = self.out_of_memory.pop();
+        let internal = self.internal.pop();
+        let errors: Vec<_> = [
+            pollster::block_on(validation),
+            pollster::block_on(out_of_memory),
+            pollster::block_on(internal),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|error| Log {
+            level: LogLevel::Error,
+            message: format!("GPU error during {operation}"),
+            location: None,
+            inner: error
+                .to_string()
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(|line| LogInner {
+                    level: LogLevel::Info,
+                    message: line.into(),
+                    location: None,
+                })
+                .collect(),
+        })
+        .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    // errors of the shader are completed with the location of the GPEx code at their origin
+    pub(crate) fn pop_shader(
+        self,
+        operation: &str,
+        module: &ShaderModule,
+        code: &str,
+        source_map: &SourceMap,
+    ) -> Result<(), Vec<Log>> {
+        self.pop(operation).map_err(|mut errors| {
+            let info = pollster::block_on(module.get_compilation_info());
+            let source_locations = info
+                .messages
+                .iter()
+                .filter(|message| message.message_type == CompilationMessageType::Error)
+                .filter_map(|message| message.location)
+                .filter_map(|location| {
+                    source_map.find(
+                        code,
+                        location.line_number as usize,
+                        location.line_position as usize,
+                    )
+                })
+                .map(|mapping| {
+                    if let Some(location) =
+                        source_location(&mapping.path, mapping.line, mapping.column, 1)
+                    {
+                        LogInner {
+                            level: LogLevel::Info,
+                            message: format!("shader code generated from `{}`", mapping.item),
+                            location: Some(location),
+                        }
+                    } else {
+                        LogInner {
+                            level: LogLevel::Info,
+                            message: format!(
+                                "shader code generated from `{}` (at {}:{}:{})",
+                                mapping.item,
+                                mapping.path.display(),
+                                mapping.line,
+                                mapping.column
+                            ),
+                            location: None,
+                        }
+                    }
+                });
+            if let Some(error) = errors.first_mut() {
+                error.inner.extend(source_locations);
+            }
+            errors
+        })
+    }
+}
+
+// `None` is returned if the GPEx source file cannot be read or is too short to contain the location
+pub(crate) fn source_location(
+    path: &Path,
+    line: usize,
+    column: usize,
+    len: usize,
+) -> Option<LogLocation> {
+    let code = fs::read_to_string(path).ok()?;
+    let offset = source_map::offset(&code, line, column)?;
+    Some(LogLocation {
+        path: path.into(),
+        span: offset..(offset + len).min(code.len()),
+        code,
+    })
+}
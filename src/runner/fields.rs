@@ -0,0 +1,47 @@
+use crate::compiler::transpilation::{Buffer, BufferField};
+use crate::runner::GpuValue;
+use crate::{Log, LogLevel};
+
+pub(crate) fn select(buffer: &Buffer, paths: &[&str]) -> Vec<(String, BufferField)> {
+    paths
+        .iter()
+        .filter_map(|&path| {
+            let field = buffer.fields.get(path)?;
+            Some((path.into(), field.clone()))
+        })
+        .collect()
+}
+
+pub(crate) fn all_paths(buffer: &Buffer) -> Vec<&str> {
+    buffer.fields.keys().map(String::as_str).collect()
+}
+
+// returns the field where a value can be written
+pub(crate) fn find_writable<'buffer>(
+    buffer: &'buffer Buffer,
+    path: &str,
+    value: &GpuValue,
+) -> Result<&'buffer BufferField, Vec<Log>> {
+    let Some(field) = buffer.fields.get(path) else {
+        return Err(vec![Log {
+            level: LogLevel::Error,
+            message: format!("`{path}` variable not found"),
+            location: None,
+            inner: vec![],
+        }]);
+    };
+    if value.type_() == field.type_ {
+        Ok(field)
+    } else {
+        Err(vec![Log {
+            level: LogLevel::Error,
+            message: format!(
+                "`{}` value written in `{path}` variable of type `{}`",
+                value.type_(),
+                field.type_
+            ),
+            location: None,
+            inner: vec![],
+        }])
+    }
+}
@@ -0,0 +1,47 @@
+use crate::compiler::types::Type;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A value read from or written to GPU.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum GpuValue {
+    /// An `i32` value.
+    I32(i32),
+    /// An `f32` value.
+    F32(f32),
+}
+
+impl GpuValue {
+    /// Returns the type of the value.
+    pub fn type_(&self) -> Type {
+        match self {
+            Self::I32(_) => Type::I32,
+            Self::F32(_) => Type::F32,
+        }
+    }
+
+    pub(crate) fn from_bytes(type_: Type, bytes: &[u8]) -> Self {
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        match type_ {
+            Type::I32 => Self::I32(i32::from_ne_bytes(bytes)),
+            Type::F32 => Self::F32(f32::from_ne_bytes(bytes)),
+        }
+    }
+
+    pub(crate) fn to_bytes(&self) -> [u8; 4] {
+        match self {
+            Self::I32(value) => value.to_ne_bytes(),
+            Self::F32(value) => value.to_ne_bytes(),
+        }
+    }
+}
+
+impl Display for GpuValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::I32(value) => Display::fmt(value, f),
+            Self::F32(value) => Display::fmt(value, f),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::compiler::externs;
+use crate::compiler::externs::{ExternVariable, GAMEPAD_AXIS_COUNT, KEY_WORD_COUNT};
+use crate::{Log, LogLevel, LogLocation};
+use std::fs;
+use std::path::Path;
+
+/// The input state of a `GPEx` program.
+///
+/// Input values are exposed to the program as extern variables defined in `prelude.input` module.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Input {
+    keys: [u32; KEY_WORD_COUNT],
+    mouse_x: i32,
+    mouse_y: i32,
+    mouse_buttons: u32,
+    gamepad_axes: [i32; GAMEPAD_AXIS_COUNT],
+}
+
+impl Input {
+    /// Returns the value of an input from its name in `prelude.input` module (e.g. `key_a`).
+    ///
+    /// Keys and buttons have value `1` when pressed, `0` otherwise.
+    pub fn get(&self, name: &str) -> Option<i32> {
+        Some(match ExternVariable::from_name(name)? {
+            ExternVariable::Key(key) => {
+                let (word, bit_index) = externs::key_position(key);
+                bit(self.keys[word], bit_index)
+            }
+            ExternVariable::MouseX => self.mouse_x,
+            ExternVariable::MouseY => self.mouse_y,
+            ExternVariable::MouseButton(button) => bit(self.mouse_buttons, button),
+            ExternVariable::GamepadAxis(axis) => self.gamepad_axes[axis],
+            ExternVariable::FrameIndex
+            | ExternVariable::DeltaSeconds
+            | ExternVariable::ElapsedSeconds => return None,
+        })
+    }
+
+    /// Sets the value of an input from its name in `prelude.input` module (e.g. `key_a`).
+    ///
+    /// Keys and buttons are pressed if `value` is not zero.
+    ///
+    /// Mouse position is in pixels, relative to the top-left corner of the rendering target.
+    /// Gamepad axes are in range `[-32767, 32767]`. They are not read from any device, even when
+    /// rendering into a window, so they can only be set with this method or an [`InputScript`].
+    ///
+    /// If the input doesn't exist, `false` is returned.
+    pub fn set(&mut self, name: &str, value: i32) -> bool {
+        let Some(variable) = ExternVariable::from_name(name) else {
+            return false;
+        };
+        match variable {
+            ExternVariable::Key(key) => {
+                let (word, bit_index) = externs::key_position(key);
+                set_bit(&mut self.keys[word], bit_index, value);
+            }
+            ExternVariable::MouseX => self.mouse_x = value,
+            ExternVariable::MouseY => self.mouse_y = value,
+            ExternVariable::MouseButton(button) => set_bit(&mut self.mouse_buttons, button, value),
+            ExternVariable::GamepadAxis(axis) => self.gamepad_axes[axis] = value,
+            ExternVariable::FrameIndex
+            | ExternVariable::DeltaSeconds
+            | ExternVariable::ElapsedSeconds => return false,
+        }
+        true
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        self.keys
+            .iter()
+            .flat_map(|word| word.to_ne_bytes())
+            .chain(self.mouse_x.to_ne_bytes())
+            .chain(self.mouse_y.to_ne_bytes())
+            .chain(self.mouse_buttons.to_ne_bytes())
+            .chain(self.gamepad_axes.iter().flat_map(|axis| axis.to_ne_bytes()))
+            .collect()
+    }
+}
+
+/// A recorded sequence of input changes, used to run a `GPEx` program without user interaction.
+///
+/// Each non-empty line of a script file has the format `<step> <input name> <value>`, where
+/// `step` is the index of the step before which the input is changed, starting from zero.
+/// Lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct InputScript {
+    changes: Vec<InputChange>,
+}
+
+impl InputScript {
+    /// Loads an input script file.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the file cannot be read or contains invalid lines.
+    pub fn load(path: &Path) -> Result<Self, Vec<Log>> {
+        let code = fs::read_to_string(path)
+            .map_err(|error| vec![Log::from_io_error(error, path, "cannot read")])?;
+        let mut changes = vec![];
+        let mut errors = vec![];
+        let mut offset = 0;
+        for line in code.split_inclusive('\n') {
+            let trimmed_line = line.trim();
+            if !trimmed_line.is_empty() && !trimmed_line.starts_with('#') {
+                if let Some(change) = InputChange::parse(trimmed_line) {
+                    changes.push(change);
+                } else {
+                    let start = offset + line.len() - line.trim_start().len();
+                    errors.push(Log {
+                        level: LogLevel::Error,
+                        message: format!("invalid input script line `{trimmed_line}`"),
+                        location: Some(LogLocation {
+                            path: path.into(),
+                            code: code.clone(),
+                            span: start..start + trimmed_line.len(),
+                        }),
+                        inner: vec![],
+                    });
+                }
+            }
+            offset += line.len();
+        }
+        if errors.is_empty() {
+            changes.sort_by_key(|change| change.step);
+            Ok(Self { changes })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Applies on `input` the changes registered for a given step.
+    pub fn apply(&self, step: u32, input: &mut Input) {
+        for change in self.changes.iter().filter(|change| change.step == step) {
+            input.set(&change.name, change.value);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct InputChange {
+    step: u32,
+    name: String,
+    value: i32,
+}
+
+impl InputChange {
+    fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.split_whitespace();
+        let step = parts.next()?.parse().ok()?;
+        let name = parts.next()?;
+        let value = parts.next()?.parse().ok()?;
+        (parts.next().is_none() && Input::default().get(name).is_some()).then(|| Self {
+            step,
+            name: name.into(),
+            value,
+        })
+    }
+}
+
+fn bit(word: u32, index: u32) -> i32 {
+    ((word >> index) & 1).cast_signed()
+}
+
+fn set_bit(word: &mut u32, index: u32, value: i32) {
+    if value == 0 {
+        *word &= !(1 << index);
+    } else {
+        *word |= 1 << index;
+    }
+}
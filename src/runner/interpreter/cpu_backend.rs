@@ -0,0 +1,151 @@
+use crate::compiler::source_map::SourceMap;
+use crate::runner::backend::{Backend, BufferKind};
+use crate::runner::interpreter::evaluation;
+use crate::runner::target::DEFAULT_TARGET_SIZE;
+use crate::utils::shader;
+use crate::{Log, LogInner, LogLevel};
+use naga::Module;
+use std::mem;
+
+// backend storing buffers in memory and evaluating compute shaders on the CPU
+#[derive(Debug)]
+pub(crate) struct CpuBackend {
+    buffers: Vec<Vec<u8>>,
+    target_size: (u32, u32),
+}
+
+impl Default for CpuBackend {
+    fn default() -> Self {
+        Self {
+            buffers: vec![],
+            target_size: DEFAULT_TARGET_SIZE,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct CpuComputeShader {
+    module: Module,
+    buffer_ids: Vec<usize>,
+}
+
+impl Backend for CpuBackend {
+    type Buffer = usize;
+    type ComputeShader = CpuComputeShader;
+    type DrawShader = ();
+
+    fn create_buffer(
+        &mut self,
+        _label: &str,
+        _kind: BufferKind,
+        size: u64,
+    ) -> Result<Self::Buffer, Vec<Log>> {
+        self.buffers.push(vec![0; to_index(size)]);
+        Ok(self.buffers.len() - 1)
+    }
+
+    fn write_buffer(
+        &mut self,
+        &buffer: &Self::Buffer,
+        offset: u64,
+        bytes: &[u8],
+    ) -> Result<(), Vec<Log>> {
+        let offset = to_index(offset);
+        self.buffers[buffer][offset..offset + bytes.len()].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    fn read_buffer(&self, &buffer: &Self::Buffer, offset: u64, size: u64) -> Vec<u8> {
+        self.buffers[buffer][to_index(offset)..to_index(offset + size)].to_vec()
+    }
+
+    fn read_buffers(&self, buffers: &[(&Self::Buffer, u64)]) -> Vec<u8> {
+        buffers
+            .iter()
+            .flat_map(|&(&buffer, size)| &self.buffers[buffer][..to_index(size)])
+            .copied()
+            .collect()
+    }
+
+    fn create_compute_shader(
+        &mut self,
+        label: &str,
+        code: &str,
+        _source_map: &SourceMap,
+        buffers: &[&Self::Buffer],
+    ) -> Result<Self::ComputeShader, Vec<Log>> {
+        let (module, _) = shader::parse(code).map_err(|error| {
+            // coverage: off (shaders are validated during compilation and loading)
+            vec![Log {
+                level: LogLevel::Error,
+                message: format!("invalid {label}"),
+                location: None,
+                inner: vec![LogInner {
+                    level: LogLevel::Info,
+                    message: error.describe(code),
+                    location: None,
+                }],
+            }]
+            // coverage: on
+        })?;
+        Ok(CpuComputeShader {
+            module,
+            buffer_ids: buffers.iter().map(|&&buffer| buffer).collect(),
+        })
+    }
+
+    fn dispatch(&mut self, shader: &Self::ComputeShader) -> Result<(), Vec<Log>> {
+        // bytes of each shader binding, in binding order
+        let mut bindings: Vec<_> = shader
+            .buffer_ids
+            .iter()
+            .map(|&buffer| mem::take(&mut self.buffers[buffer]))
+            .collect();
+        let mut memory: Vec<_> = bindings.iter_mut().map(Vec::as_mut_slice).collect();
+        let result = evaluation::run(&shader.module, &mut memory);
+        for (&buffer, bytes) in shader.buffer_ids.iter().zip(bindings) {
+            self.buffers[buffer] = bytes;
+        }
+        result.map_err(|message| {
+            vec![Log {
+                level: LogLevel::Error,
+                message: "init shader cannot be interpreted".into(),
+                location: None,
+                inner: vec![LogInner {
+                    level: LogLevel::Info,
+                    message,
+                    location: None,
+                }],
+            }]
+        })
+    }
+
+    fn create_draw_shader(
+        &mut self,
+        _label: &str,
+        _code: &str,
+        _source_map: &SourceMap,
+        _buffers: &[&Self::Buffer],
+    ) -> Result<Self::DrawShader, Vec<Log>> {
+        Ok(())
+    }
+
+    fn render(&mut self, _shaders: &[Self::DrawShader]) {}
+
+    fn target_size(&self) -> (u32, u32) {
+        self.target_size
+    }
+
+    fn resize_target(&mut self, width: u32, height: u32) {
+        self.target_size = (width.max(1), height.max(1));
+    }
+
+    fn read_target(&self) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+#[expect(clippy::cast_possible_truncation)] // buffer sizes are stored as `u32` in programs
+fn to_index(value: u64) -> usize {
+    value as usize
+}
@@ -0,0 +1,580 @@
+use naga::{
+    BinaryOperator, Block, Expression, Function, Handle, Literal, MathFunction, Module, ScalarKind,
+    ShaderStage, Statement, Type, TypeInner, UnaryOperator,
+};
+use std::ops::Range;
+
+const SCALAR_BYTES: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    I32(i32),
+    U32(u32),
+    F32(f32),
+    Bool(bool),
+    Pointer(Pointer),
+}
+
+// reference to a value stored in the memory of a binding
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pointer {
+    binding: usize,
+    offset: usize,
+    type_: Handle<Type>,
+}
+
+// runs the compute entry point of a shader, where `memory` contains the bytes of each binding
+pub(crate) fn run(module: &Module, memory: &mut [&mut [u8]]) -> Result<(), String> {
+    let entry_point = module
+        .entry_points
+        .iter()
+        .find(|entry_point| entry_point.stage == ShaderStage::Compute)
+        .ok_or_else(|| "compute entry point not found".to_string())?;
+    let mut evaluation = Evaluation {
+        module,
+        function: &entry_point.function,
+        memory,
+        values: vec![None; entry_point.function.expressions.len()],
+        args: vec![],
+        return_value: None,
+    };
+    evaluation.run_block(&entry_point.function.body)?;
+    Ok(())
+}
+
+struct Evaluation<'module, 'memory, 'bytes> {
+    module: &'module Module,
+    function: &'module Function,
+    memory: &'memory mut [&'bytes mut [u8]],
+    // values of the function expressions already evaluated
+    values: Vec<Option<Value>>,
+    args: Vec<Value>,
+    return_value: Option<Value>,
+}
+
+impl Evaluation<'_, '_, '_> {
+    // returns whether the function has returned
+    fn run_block(&mut self, block: &Block) -> Result<bool, String> {
+        for statement in block {
+            #[expect(clippy::wildcard_enum_match_arm)] // other statements are never generated
+            match *statement {
+                Statement::Emit(ref range) => {
+                    for handle in range.clone() {
+                        self.evaluate(handle, false)?;
+                    }
+                }
+                Statement::Block(ref block) => {
+                    if self.run_block(block)? {
+                        return Ok(true);
+                    }
+                }
+                Statement::If {
+                    condition,
+                    ref accept,
+                    ref reject,
+                } => {
+                    let Value::Bool(condition) = self.evaluate(condition, false)? else {
+                        return Err("invalid `if` condition".into()); // no-coverage (invalid shader)
+                    };
+                    if self.run_block(if condition { accept } else { reject })? {
+                        return Ok(true);
+                    }
+                }
+                Statement::Store { pointer, value } => {
+                    let pointer = self.evaluate(pointer, false)?;
+                    let value = self.evaluate(value, false)?;
+                    self.store(pointer, value)?;
+                }
+                Statement::Call {
+                    function,
+                    arguments: ref args,
+                    result,
+                } => {
+                    let args = args
+                        .iter()
+                        .map(|&arg| self.evaluate(arg, false))
+                        .collect::<Result<_, _>>()?;
+                    let value = self.call(&self.module.functions[function], args)?;
+                    if let Some(result) = result {
+                        self.values[result.index()] = Some(value);
+                    }
+                }
+                Statement::Return { value } => {
+                    self.return_value =
+                        value.map(|value| self.evaluate(value, false)).transpose()?;
+                    return Ok(true);
+                }
+                // coverage: off (not generated by the compiler)
+                _ => return Err(format!("unsupported statement `{statement:?}`")),
+                // coverage: on
+            }
+        }
+        Ok(false)
+    }
+
+    // calls a helper function defined in the shader
+    fn call(&mut self, function: &Function, args: Vec<Value>) -> Result<Value, String> {
+        let mut evaluation = Evaluation {
+            module: self.module,
+            function,
+            memory: self.memory,
+            values: vec![None; function.expressions.len()],
+            args,
+            return_value: None,
+        };
+        evaluation.run_block(&function.body)?;
+        evaluation
+            .return_value
+            .ok_or_else(|| "missing return value".into()) // no-coverage (invalid shader)
+    }
+
+    fn evaluate(&mut self, handle: Handle<Expression>, is_global: bool) -> Result<Value, String> {
+        if is_global {
+            return self.compute(&self.module.global_expressions[handle], true);
+        }
+        if let Some(value) = self.values[handle.index()] {
+            return Ok(value);
+        }
+        let function = self.function;
+        let value = self.compute(&function.expressions[handle], false)?;
+        self.values[handle.index()] = Some(value);
+        Ok(value)
+    }
+
+    fn compute(&mut self, expression: &Expression, is_global: bool) -> Result<Value, String> {
+        #[expect(clippy::wildcard_enum_match_arm)] // other expressions are never generated
+        match *expression {
+            Expression::Literal(literal) => literal_value(literal),
+            Expression::Constant(constant) => {
+                self.evaluate(self.module.constants[constant].init, true)
+            }
+            Expression::ZeroValue(type_) => self.zero_value(type_),
+            Expression::FunctionArgument(index) => self
+                .args
+                .get(index as usize)
+                .copied()
+                .ok_or_else(|| "invalid function argument".into()),
+            Expression::GlobalVariable(variable) => {
+                let variable = &self.module.global_variables[variable];
+                let binding = variable
+                    .binding
+                    .as_ref()
+                    .ok_or_else(|| "unbound global variable".to_string())?;
+                Ok(Value::Pointer(Pointer {
+                    binding: binding.binding as usize,
+                    offset: 0,
+                    type_: variable.ty,
+                }))
+            }
+            Expression::AccessIndex { base, index } => {
+                let base = self.evaluate(base, is_global)?;
+                self.access(base, index as usize)
+            }
+            Expression::Access { base, index } => {
+                let base = self.evaluate(base, is_global)?;
+                let index = match self.evaluate(index, is_global)? {
+                    Value::I32(index) => usize::try_from(index).ok(),
+                    Value::U32(index) => usize::try_from(index).ok(),
+                    Value::F32(_) | Value::Bool(_) | Value::Pointer(_) => None,
+                };
+                self.access(base, index.ok_or_else(|| "invalid index".to_string())?)
+            }
+            Expression::Load { pointer } => {
+                let pointer = self.evaluate(pointer, is_global)?;
+                self.load(pointer)
+            }
+            Expression::Unary { op: operator, expr } => {
+                unary(operator, self.evaluate(expr, is_global)?)
+            }
+            Expression::Binary {
+                op: operator,
+                left,
+                right,
+            } => {
+                let left = self.evaluate(left, is_global)?;
+                let right = self.evaluate(right, is_global)?;
+                binary(operator, left, right)
+            }
+            Expression::Select {
+                condition,
+                accept,
+                reject,
+            } => match self.evaluate(condition, is_global)? {
+                Value::Bool(true) => self.evaluate(accept, is_global),
+                Value::Bool(false) => self.evaluate(reject, is_global),
+                // coverage: off (invalid shader)
+                Value::I32(_) | Value::U32(_) | Value::F32(_) | Value::Pointer(_) => {
+                    Err("invalid `select` condition".into())
+                } // coverage: on
+            },
+            Expression::As {
+                expr,
+                kind,
+                convert,
+            } => {
+                let value = self.evaluate(expr, is_global)?;
+                if convert.is_some() {
+                    convert_value(value, kind)
+                } else {
+                    bitcast_value(value, kind)
+                }
+            }
+            Expression::Math {
+                fun,
+                arg,
+                arg1,
+                arg2,
+                ..
+            } => {
+                let mut args = vec![self.evaluate(arg, is_global)?];
+                for arg in [arg1, arg2].into_iter().flatten() {
+                    args.push(self.evaluate(arg, is_global)?);
+                }
+                math(fun, &args)
+            }
+            // coverage: off (not generated by the compiler)
+            _ => Err(format!("unsupported expression `{expression:?}`")),
+            // coverage: on
+        }
+    }
+
+    #[expect(clippy::wildcard_enum_match_arm)] // other types are never generated
+    fn zero_value(&self, type_: Handle<Type>) -> Result<Value, String> {
+        match self.module.types[type_].inner {
+            TypeInner::Scalar(scalar) => match scalar.kind {
+                ScalarKind::Sint => Ok(Value::I32(0)),
+                ScalarKind::Uint => Ok(Value::U32(0)),
+                ScalarKind::Float => Ok(Value::F32(0.)),
+                ScalarKind::Bool => Ok(Value::Bool(false)),
+                // coverage: off (not generated by the compiler)
+                ScalarKind::AbstractInt | ScalarKind::AbstractFloat => {
+                    Err("unsupported abstract zero value".into())
+                } // coverage: on
+            },
+            ref inner => Err(format!("unsupported zero value of type `{inner:?}`")), // no-coverage (not generated by the compiler)
+        }
+    }
+
+    fn access(&self, base: Value, index: usize) -> Result<Value, String> {
+        let Value::Pointer(pointer) = base else {
+            return Err("unsupported access to a non-pointer value".into()); // no-coverage (not generated by the compiler)
+        };
+        #[expect(clippy::wildcard_enum_match_arm)] // other types are never accessed
+        match self.module.types[pointer.type_].inner {
+            TypeInner::Struct { ref members, .. } => {
+                let member = members
+                    .get(index)
+                    .ok_or_else(|| "invalid struct member".to_string())?;
+                Ok(Value::Pointer(Pointer {
+                    offset: pointer.offset + member.offset as usize,
+                    type_: member.ty,
+                    ..pointer
+                }))
+            }
+            TypeInner::Array { base, stride, .. } => Ok(Value::Pointer(Pointer {
+                offset: pointer.offset + index * stride as usize,
+                type_: base,
+                ..pointer
+            })),
+            // coverage: off (not generated by the compiler)
+            ref inner => Err(format!("unsupported access to a value of type `{inner:?}`")),
+            // coverage: on
+        }
+    }
+
+    fn load(&self, pointer: Value) -> Result<Value, String> {
+        let (binding, range, kind) = self.scalar_bytes(pointer)?;
+        let mut bytes = [0; SCALAR_BYTES];
+        bytes.copy_from_slice(&self.memory[binding][range]);
+        match kind {
+            ScalarKind::Sint => Ok(Value::I32(i32::from_ne_bytes(bytes))),
+            ScalarKind::Uint => Ok(Value::U32(u32::from_ne_bytes(bytes))),
+            ScalarKind::Float => Ok(Value::F32(f32::from_ne_bytes(bytes))),
+            // coverage: off (not generated by the compiler)
+            ScalarKind::Bool | ScalarKind::AbstractInt | ScalarKind::AbstractFloat => {
+                Err("unsupported load of a non-numeric value".into())
+            } // coverage: on
+        }
+    }
+
+    fn store(&mut self, pointer: Value, value: Value) -> Result<(), String> {
+        let (binding, range, _) = self.scalar_bytes(pointer)?;
+        let bytes = match value {
+            Value::I32(value) => value.to_ne_bytes(),
+            Value::U32(value) => value.to_ne_bytes(),
+            Value::F32(value) => value.to_ne_bytes(),
+            // coverage: off (not generated by the compiler)
+            Value::Bool(_) | Value::Pointer(_) => {
+                return Err("unsupported store of a non-numeric value".into());
+            } // coverage: on
+        };
+        self.memory[binding][range].copy_from_slice(&bytes);
+        Ok(())
+    }
+
+    // returns the binding index, the byte range and the kind of a pointed scalar
+    fn scalar_bytes(&self, pointer: Value) -> Result<(usize, Range<usize>, ScalarKind), String> {
+        let Value::Pointer(pointer) = pointer else {
+            return Err("invalid pointer".into()); // no-coverage (invalid shader)
+        };
+        let TypeInner::Scalar(scalar) = self.module.types[pointer.type_].inner else {
+            return Err("unsupported access to a non-scalar value".into()); // no-coverage (not generated by the compiler)
+        };
+        let range = pointer.offset..pointer.offset + SCALAR_BYTES;
+        let is_valid = self
+            .memory
+            .get(pointer.binding)
+            .is_some_and(|memory| range.end <= memory.len());
+        if is_valid {
+            Ok((pointer.binding, range, scalar.kind))
+        } else {
+            Err("out of bounds memory access".into()) // no-coverage (invalid shader)
+        }
+    }
+}
+
+fn literal_value(literal: Literal) -> Result<Value, String> {
+    match literal {
+        Literal::I32(value) => Ok(Value::I32(value)),
+        Literal::U32(value) => Ok(Value::U32(value)),
+        Literal::F32(value) => Ok(Value::F32(value)),
+        Literal::Bool(value) => Ok(Value::Bool(value)),
+        // coverage: off (not generated by the compiler)
+        Literal::F64(_)
+        | Literal::F16(_)
+        | Literal::U64(_)
+        | Literal::I64(_)
+        | Literal::AbstractInt(_)
+        | Literal::AbstractFloat(_) => Err(format!("unsupported literal `{literal:?}`")),
+        // coverage: on
+    }
+}
+
+fn unary(operator: UnaryOperator, value: Value) -> Result<Value, String> {
+    match (operator, value) {
+        (UnaryOperator::Negate, Value::I32(value)) => Ok(Value::I32(value.wrapping_neg())),
+        (UnaryOperator::Negate, Value::F32(value)) => Ok(Value::F32(-value)),
+        (UnaryOperator::BitwiseNot, Value::I32(value)) => Ok(Value::I32(!value)),
+        (UnaryOperator::BitwiseNot, Value::U32(value)) => Ok(Value::U32(!value)),
+        (UnaryOperator::LogicalNot, Value::Bool(value)) => Ok(Value::Bool(!value)),
+        // coverage: off (invalid shader)
+        (UnaryOperator::Negate | UnaryOperator::BitwiseNot | UnaryOperator::LogicalNot, _) => Err(
+            format!("unsupported `{operator:?}` operation on `{value:?}`"),
+        ), // coverage: on
+    }
+}
+
+#[expect(clippy::wildcard_enum_match_arm)] // other operations are not supported
+fn binary(operator: BinaryOperator, left: Value, right: Value) -> Result<Value, String> {
+    let result = match (left, right) {
+        (Value::I32(left), Value::I32(right)) => match operator {
+            BinaryOperator::Add => Some(Value::I32(left.wrapping_add(right))),
+            BinaryOperator::Subtract => Some(Value::I32(left.wrapping_sub(right))),
+            BinaryOperator::Multiply => Some(Value::I32(left.wrapping_mul(right))),
+            BinaryOperator::Divide => Some(Value::I32(if right == 0 {
+                left
+            } else {
+                left.wrapping_div(right)
+            })),
+            BinaryOperator::Modulo => Some(Value::I32(if right == 0 {
+                0
+            } else {
+                left.wrapping_rem(right)
+            })),
+            BinaryOperator::And => Some(Value::I32(left & right)),
+            BinaryOperator::ExclusiveOr => Some(Value::I32(left ^ right)),
+            BinaryOperator::InclusiveOr => Some(Value::I32(left | right)),
+            _ => compare(operator, left, right),
+        },
+        (Value::U32(left), Value::U32(right)) => match operator {
+            BinaryOperator::Add => Some(Value::U32(left.wrapping_add(right))),
+            BinaryOperator::Subtract => Some(Value::U32(left.wrapping_sub(right))),
+            BinaryOperator::Multiply => Some(Value::U32(left.wrapping_mul(right))),
+            BinaryOperator::Divide => Some(Value::U32(left.checked_div(right).unwrap_or(left))),
+            BinaryOperator::Modulo => Some(Value::U32(left.checked_rem(right).unwrap_or(0))),
+            BinaryOperator::And => Some(Value::U32(left & right)),
+            BinaryOperator::ExclusiveOr => Some(Value::U32(left ^ right)),
+            BinaryOperator::InclusiveOr => Some(Value::U32(left | right)),
+            BinaryOperator::ShiftLeft => Some(Value::U32(left.wrapping_shl(right))),
+            BinaryOperator::ShiftRight => Some(Value::U32(left.wrapping_shr(right))),
+            _ => compare(operator, left, right),
+        },
+        (Value::I32(left), Value::U32(right)) => match operator {
+            BinaryOperator::ShiftLeft => Some(Value::I32(left.wrapping_shl(right))),
+            BinaryOperator::ShiftRight => Some(Value::I32(left.wrapping_shr(right))),
+            _ => None,
+        },
+        (Value::F32(left), Value::F32(right)) => match operator {
+            BinaryOperator::Add => Some(Value::F32(left + right)),
+            BinaryOperator::Subtract => Some(Value::F32(left - right)),
+            BinaryOperator::Multiply => Some(Value::F32(left * right)),
+            BinaryOperator::Divide => Some(Value::F32(left / right)),
+            BinaryOperator::Modulo => Some(Value::F32(left % right)),
+            _ => compare(operator, left, right),
+        },
+        (Value::Bool(left), Value::Bool(right)) => match operator {
+            BinaryOperator::LogicalAnd | BinaryOperator::And => Some(Value::Bool(left && right)),
+            BinaryOperator::LogicalOr | BinaryOperator::InclusiveOr => {
+                Some(Value::Bool(left || right))
+            }
+            _ => compare(operator, left, right),
+        },
+        _ => None,
+    };
+    result.ok_or_else(|| {
+        format!("unsupported `{operator:?}` operation on `{left:?}` and `{right:?}`")
+    })
+}
+
+#[expect(clippy::wildcard_enum_match_arm)] // other operators are not comparisons
+fn compare<T: PartialOrd>(operator: BinaryOperator, left: T, right: T) -> Option<Value> {
+    Some(Value::Bool(match operator {
+        BinaryOperator::Equal => left == right,
+        BinaryOperator::NotEqual => left != right,
+        BinaryOperator::Less => left < right,
+        BinaryOperator::LessEqual => left <= right,
+        BinaryOperator::Greater => left > right,
+        BinaryOperator::GreaterEqual => left >= right,
+        _ => return None,
+    }))
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)] // same behavior as WGSL conversions
+fn convert_value(value: Value, kind: ScalarKind) -> Result<Value, String> {
+    match (value, kind) {
+        (Value::I32(value), ScalarKind::Sint) => Ok(Value::I32(value)),
+        (Value::U32(value), ScalarKind::Sint) => Ok(Value::I32(value.cast_signed())),
+        (Value::F32(value), ScalarKind::Sint) => Ok(Value::I32(value as i32)),
+        (Value::Bool(value), ScalarKind::Sint) => Ok(Value::I32(value.into())),
+        (Value::I32(value), ScalarKind::Uint) => Ok(Value::U32(value.cast_unsigned())),
+        (Value::U32(value), ScalarKind::Uint) => Ok(Value::U32(value)),
+        (Value::F32(value), ScalarKind::Uint) => Ok(Value::U32(value as u32)),
+        (Value::Bool(value), ScalarKind::Uint) => Ok(Value::U32(value.into())),
+        (Value::I32(value), ScalarKind::Float) => Ok(Value::F32(value as f32)),
+        (Value::U32(value), ScalarKind::Float) => Ok(Value::F32(value as f32)),
+        (Value::F32(value), ScalarKind::Float) => Ok(Value::F32(value)),
+        (Value::Bool(value), ScalarKind::Float) => Ok(Value::F32(u8::from(value).into())),
+        (Value::I32(value), ScalarKind::Bool) => Ok(Value::Bool(value != 0)),
+        (Value::U32(value), ScalarKind::Bool) => Ok(Value::Bool(value != 0)),
+        (Value::F32(value), ScalarKind::Bool) => Ok(Value::Bool(value != 0.)),
+        (Value::Bool(value), ScalarKind::Bool) => Ok(Value::Bool(value)),
+        // coverage: off (not generated by the compiler)
+        (Value::Pointer(_), _) | (_, ScalarKind::AbstractInt | ScalarKind::AbstractFloat) => Err(
+            format!("unsupported conversion of `{value:?}` to `{kind:?}`"),
+        ), // coverage: on
+    }
+}
+
+fn bitcast_value(value: Value, kind: ScalarKind) -> Result<Value, String> {
+    let bits = match value {
+        Value::I32(value) => value.cast_unsigned(),
+        Value::U32(value) => value,
+        Value::F32(value) => value.to_bits(),
+        // coverage: off (not generated by the compiler)
+        Value::Bool(_) | Value::Pointer(_) => {
+            return Err(format!("unsupported bitcast of `{value:?}`"));
+        } // coverage: on
+    };
+    match kind {
+        ScalarKind::Sint => Ok(Value::I32(bits.cast_signed())),
+        ScalarKind::Uint => Ok(Value::U32(bits)),
+        ScalarKind::Float => Ok(Value::F32(f32::from_bits(bits))),
+        // coverage: off (not generated by the compiler)
+        ScalarKind::Bool | ScalarKind::AbstractInt | ScalarKind::AbstractFloat => {
+            Err(format!("unsupported bitcast to `{kind:?}`"))
+        } // coverage: on
+    }
+}
+
+#[expect(clippy::wildcard_enum_match_arm)] // other functions are not supported
+fn math(fun: MathFunction, args: &[Value]) -> Result<Value, String> {
+    let result = match *args {
+        [Value::I32(value)] => integer_math(fun, value.cast_unsigned())
+            .map(|result| Value::I32(result.cast_signed()))
+            .or_else(|| match fun {
+                MathFunction::Abs => Some(Value::I32(value.wrapping_abs())),
+                MathFunction::Sign => Some(Value::I32(value.signum())),
+                _ => None,
+            }),
+        [Value::U32(value)] => integer_math(fun, value)
+            .map(Value::U32)
+            .or_else(|| (fun == MathFunction::Abs).then_some(Value::U32(value))),
+        [Value::F32(value)] => float_math(fun, value).map(Value::F32),
+        [Value::I32(first), Value::I32(second)] => {
+            ordering_math(fun, first, second).map(Value::I32)
+        }
+        [Value::U32(first), Value::U32(second)] => {
+            ordering_math(fun, first, second).map(Value::U32)
+        }
+        [Value::F32(first), Value::F32(second)] => match fun {
+            MathFunction::Min => Some(Value::F32(first.min(second))),
+            MathFunction::Max => Some(Value::F32(first.max(second))),
+            MathFunction::Pow => Some(Value::F32(first.powf(second))),
+            MathFunction::Atan2 => Some(Value::F32(first.atan2(second))),
+            _ => None,
+        },
+        [Value::I32(value), Value::I32(low), Value::I32(high)] if fun == MathFunction::Clamp => {
+            Some(Value::I32(value.max(low).min(high)))
+        }
+        [Value::U32(value), Value::U32(low), Value::U32(high)] if fun == MathFunction::Clamp => {
+            Some(Value::U32(value.max(low).min(high)))
+        }
+        [Value::F32(value), Value::F32(low), Value::F32(high)] if fun == MathFunction::Clamp => {
+            Some(Value::F32(value.max(low).min(high)))
+        }
+        [Value::F32(first), Value::F32(second), Value::F32(ratio)] if fun == MathFunction::Mix => {
+            Some(Value::F32(first.mul_add(1. - ratio, second * ratio)))
+        }
+        _ => None,
+    };
+    result.ok_or_else(|| format!("unsupported `{fun:?}` function on `{args:?}`"))
+}
+
+#[expect(clippy::wildcard_enum_match_arm)] // other functions are handled by the caller
+fn integer_math(fun: MathFunction, value: u32) -> Option<u32> {
+    match fun {
+        MathFunction::CountOneBits => Some(value.count_ones()),
+        MathFunction::CountLeadingZeros => Some(value.leading_zeros()),
+        MathFunction::CountTrailingZeros => Some(value.trailing_zeros()),
+        MathFunction::ReverseBits => Some(value.reverse_bits()),
+        _ => None,
+    }
+}
+
+#[expect(clippy::wildcard_enum_match_arm)] // other functions are handled by the caller
+fn ordering_math<T: Ord>(fun: MathFunction, first: T, second: T) -> Option<T> {
+    match fun {
+        MathFunction::Min => Some(first.min(second)),
+        MathFunction::Max => Some(first.max(second)),
+        _ => None,
+    }
+}
+
+#[expect(clippy::wildcard_enum_match_arm)] // other functions are handled by the caller
+fn float_math(fun: MathFunction, value: f32) -> Option<f32> {
+    match fun {
+        MathFunction::Abs => Some(value.abs()),
+        MathFunction::Sign => Some(if value == 0. { 0. } else { value.signum() }),
+        MathFunction::Floor => Some(value.floor()),
+        MathFunction::Ceil => Some(value.ceil()),
+        MathFunction::Round => Some(value.round_ties_even()),
+        MathFunction::Trunc => Some(value.trunc()),
+        MathFunction::Fract => Some(value - value.floor()),
+        MathFunction::Sqrt => Some(value.sqrt()),
+        MathFunction::Exp => Some(value.exp()),
+        MathFunction::Exp2 => Some(value.exp2()),
+        MathFunction::Log => Some(value.ln()),
+        MathFunction::Log2 => Some(value.log2()),
+        MathFunction::Sin => Some(value.sin()),
+        MathFunction::Cos => Some(value.cos()),
+        MathFunction::Tan => Some(value.tan()),
+        MathFunction::Asin => Some(value.asin()),
+        MathFunction::Acos => Some(value.acos()),
+        MathFunction::Atan => Some(value.atan()),
+        _ => None,
+    }
+}
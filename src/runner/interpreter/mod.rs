@@ -0,0 +1,79 @@
+mod cpu_backend;
+mod evaluation;
+
+use crate::Log;
+use crate::compiler::transpilation::Program;
+use crate::runner::interpreter::cpu_backend::CpuBackend;
+use crate::runner::{GpuValue, Input, ProgramRunner, Runner};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// A `GPEx` program runner evaluating programs on the CPU.
+///
+/// No graphic adapter is needed. Global variables are stored with the same buffer layout as
+/// with [`Runner`](crate::Runner), so that results of both backends can be compared.
+/// Draw items are not rendered.
+#[derive(Debug)]
+pub struct Interpreter {
+    runner: Runner<CpuBackend>,
+}
+
+impl Interpreter {
+    /// Creates a new interpreter.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the program cannot be interpreted.
+    pub fn new(program: Program) -> Result<Self, Vec<Log>> {
+        Ok(Self {
+            runner: Runner::with_backend(program, CpuBackend::default())?,
+        })
+    }
+}
+
+impl ProgramRunner for Interpreter {
+    fn read_variable(&self, path: &str) -> Option<GpuValue> {
+        self.runner.read_variable(path)
+    }
+
+    fn read_variables(&self, paths: &[&str]) -> HashMap<String, GpuValue> {
+        self.runner.read_variables(paths)
+    }
+
+    fn snapshot(&self) -> HashMap<String, GpuValue> {
+        self.runner.snapshot()
+    }
+
+    fn write_variable(&mut self, path: &str, value: GpuValue) -> Result<(), Vec<Log>> {
+        self.runner.write_variable(path, value)
+    }
+
+    fn save_state(&self, path: &Path) -> Result<(), Vec<Log>> {
+        self.runner.save_state(path)
+    }
+
+    fn load_state(&mut self, path: &Path) -> Result<Vec<Log>, Vec<Log>> {
+        self.runner.load_state(path)
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        self.runner.input_mut()
+    }
+
+    fn frame_index(&self) -> u32 {
+        self.runner.frame_index()
+    }
+
+    fn set_fixed_timestep(&mut self, timestep: Option<Duration>) {
+        self.runner.set_fixed_timestep(timestep);
+    }
+
+    fn run_step(&mut self) -> Result<(), Vec<Log>> {
+        self.runner.run_step()
+    }
+
+    fn take_debug_logs(&mut self) -> Vec<Log> {
+        self.runner.take_debug_logs()
+    }
+}
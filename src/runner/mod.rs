@@ -1,64 +1,331 @@
+mod adapter;
+mod backend;
+mod debug_logs;
+mod errors;
+mod fields;
+mod gpu_value;
+mod input;
+mod interpreter;
+mod profiler;
+mod program_runner;
 mod resources;
+mod snapshot;
+mod state;
+mod target;
+mod testing;
+mod time;
 mod utils;
+mod wgpu_backend;
 
-use crate::compiler::transpilation::Program;
-use crate::runner::resources::ComputeShader;
+use crate::compiler::debug;
+use crate::compiler::serialization;
+use crate::compiler::transpilation::{Buffer, BufferField, Program, ShaderId};
+pub use crate::runner::adapter::{AdapterOptions, available_adapters};
+pub use crate::runner::backend::{Backend, BufferKind};
+pub use crate::runner::gpu_value::GpuValue;
+pub use crate::runner::input::{Input, InputScript};
+pub use crate::runner::interpreter::Interpreter;
+pub use crate::runner::profiler::{Profile, ProfileSample, ShaderTimings};
+pub use crate::runner::program_runner::ProgramRunner;
+pub use crate::runner::resources::{WgpuComputeShader, WgpuDrawShader};
+pub use crate::runner::snapshot::PendingSnapshot;
+use crate::runner::state::State;
+use crate::runner::target::Target;
+pub use crate::runner::testing::{TestReport, TestResult, TestSuite};
+use crate::runner::time::{TIME_BYTES, Time};
+pub use crate::runner::wgpu_backend::WgpuBackend;
+use crate::utils::image;
 use crate::{Log, LogLevel};
-use std::fmt::{Display, Formatter};
+use std::collections::HashMap;
 use std::fs;
+use std::mem;
 use std::path::Path;
-use wgpu::{Buffer, Device, Queue};
+use std::time::Duration;
+use wgpu::{CommandEncoder, Device, Queue, SurfaceTarget};
+
+const TARGET_SIZE_BYTES: u64 = 8;
 
 /// Loads a compiled `GPEx` program.
 ///
+/// The file format is detected automatically.
+///
 /// # Errors
 ///
 /// An error is returned in case the input file is not a valid compiled `GPEx` program.
 pub fn load_compiled(path: &Path) -> Result<Program, Vec<Log>> {
-    match fs::read_to_string(path) {
-        Ok(content) => Ok(serde_json::from_str(&content).map_err(|_| {
-            vec![Log {
-                level: LogLevel::Error,
-                message: format!("invalid compiled program \"{}\"", path.display()),
-                location: None,
-                inner: vec![],
-            }]
-        })?),
+    match fs::read(path) {
+        Ok(content) => serialization::deserialize(&content, path),
         Err(error) => Err(vec![Log::from_io_error(error, path, "cannot read")]),
     }
 }
 
+// buffers storing the values of extern variables, written by the runner before each step
+#[derive(Debug)]
+struct ExternBuffers<T> {
+    input: T,
+    time: T,
+}
+
 /// A `GPEx` program runner.
+///
+/// GPU operations are executed by a [`Backend`], by default [`WgpuBackend`].
 #[derive(Debug)]
-pub struct Runner {
+pub struct Runner<B: Backend = WgpuBackend> {
     program: Program,
-    device: Device,
-    queue: Queue,
-    buffer: Option<Buffer>,
-    init_shader: Option<ComputeShader>,
+    backend: B,
+    buffers: Vec<B::Buffer>,
+    init_shader: Option<B::ComputeShader>,
+    is_init_done: bool,
+    target_size_buffer: B::Buffer,
+    draw_shaders: Vec<B::DrawShader>,
+    input: Input,
+    time: Time,
+    extern_buffers: ExternBuffers<B::Buffer>,
+    debug_buffer: Option<B::Buffer>,
+    debug_logs: Vec<Log>,
 }
 
 impl Runner {
-    /// Creates a new runner.
+    /// Creates a new runner rendering into an offscreen texture.
     ///
     /// # Errors
     ///
-    /// An error is returned in case the program cannot be initialized.
+    /// An error is returned in case the adapter doesn't support the limits required by the program
+    /// or the program cannot be initialized.
     pub async fn new(program: Program) -> Result<Self, Vec<Log>> {
-        let instance = utils::create_instance();
-        let adapter = utils::create_adapter(&instance).await?;
-        let (device, queue) = utils::create_device(&adapter).await?;
-        let buffer = utils::create_buffer(&device, "gpex:buffer:main", program.buffer.size.into());
-        let init_shader = buffer
-            .as_ref()
-            .map(|buffer| ComputeShader::new(&device, buffer, &program.init_shader));
-        Ok(Self {
+        Self::with_adapter(program, &AdapterOptions::default()).await
+    }
+
+    /// Creates a new runner rendering into an offscreen texture, with a graphic adapter selected
+    /// using `options`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case no adapter matches the options, the adapter doesn't support the
+    /// limits required by the program or the program cannot be initialized.
+    pub async fn with_adapter(
+        program: Program,
+        options: &AdapterOptions,
+    ) -> Result<Self, Vec<Log>> {
+        let instance = utils::create_instance(options.backends());
+        let adapter = adapter::select_adapter(&instance, None, options).await?;
+        let (device, queue) = utils::create_device(&adapter, options.is_profiling_enabled).await?;
+        utils::check_limits(&device, &program)?;
+        let mut backend = WgpuBackend::new(device, queue);
+        if options.is_profiling_enabled {
+            backend.enable_profiling();
+        }
+        Self::with_backend(program, backend)
+    }
+
+    /// Creates a new runner from an existing device, rendering into an offscreen texture.
+    ///
+    /// The main buffer is a storage buffer that can be bound to shaders of the caller, for
+    /// example to read the program state in custom render passes.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the device doesn't support the limits required by the program
+    /// or the program cannot be initialized.
+    pub fn with_device(program: Program, device: Device, queue: Queue) -> Result<Self, Vec<Log>> {
+        utils::check_limits(&device, &program)?;
+        Self::with_backend(program, WgpuBackend::new(device, queue))
+    }
+
+    /// Creates a new runner rendering into a window.
+    ///
+    /// `width` and `height` are the size in pixels of the window surface.
+    /// The graphic adapter is selected using `options`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the adapter doesn't support the limits required by the program,
+    /// or the program or the window surface cannot be initialized.
+    // coverage: off (difficult to test)
+    pub async fn with_window(
+        program: Program,
+        window: impl Into<SurfaceTarget<'static>>,
+        width: u32,
+        height: u32,
+        options: &AdapterOptions,
+    ) -> Result<Self, Vec<Log>> {
+        let instance = utils::create_instance(options.backends());
+        let surface = instance.create_surface(window).map_err(|error| {
+            vec![Log {
+                level: LogLevel::Error,
+                message: format!("cannot create window surface: {error}"),
+                location: None,
+                inner: vec![],
+            }]
+        })?;
+        let adapter = adapter::select_adapter(&instance, Some(&surface), options).await?;
+        let (device, queue) = utils::create_device(&adapter, options.is_profiling_enabled).await?;
+        utils::check_limits(&device, &program)?;
+        let target = Target::new_surface(surface, &adapter, &device, (width, height))?;
+        let mut backend = WgpuBackend::with_target(device, queue, target);
+        if options.is_profiling_enabled {
+            backend.enable_profiling();
+        }
+        Self::with_backend(program, backend)
+    }
+    // coverage: on
+
+    /// Records a program step in a command encoder instead of submitting it.
+    ///
+    /// Extern variables are written with the queue, so they are available once the encoder is
+    /// submitted. Errors of the recorded commands are reported by `wgpu` when the encoder is
+    /// finished or submitted. Assertions of the program are not checked, and debug prints are not
+    /// retrieved.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the GPU reports an error while writing extern variables.
+    pub fn record_step(&mut self, encoder: &mut CommandEncoder) -> Result<(), Vec<Log>> {
+        let is_init_run = self.start_step()?;
+        if is_init_run && let Some(shader) = &self.init_shader {
+            shader.run(&mut utils::start_compute_pass(encoder, None));
+        }
+        self.finish_step(is_init_run);
+        Ok(())
+    }
+
+    /// Starts reading the values of several global variables without blocking.
+    ///
+    /// Values can then be retrieved with [`PendingSnapshot::try_finish`], for example once per
+    /// frame until they are available.
+    pub fn start_read_variables(&self, paths: &[&str]) -> PendingSnapshot {
+        let parts: Vec<_> = self
+            .buffers
+            .iter()
+            .zip(&self.program.buffer.parts)
+            .map(|(buffer, part)| (buffer, part.size.into()))
+            .collect();
+        let read = (!parts.is_empty())
+            .then(|| utils::start_buffer_read(&self.backend.device, &self.backend.queue, &parts));
+        PendingSnapshot::new(
+            read,
+            fields::select(&self.program.buffer, paths),
+            self.backend.device.clone(),
+        )
+    }
+
+    /// Starts reading the values of all global variables without blocking.
+    pub fn start_snapshot(&self) -> PendingSnapshot {
+        self.start_read_variables(&fields::all_paths(&self.program.buffer))
+    }
+}
+
+impl<B: Backend> Runner<B> {
+    /// Creates a new runner executing GPU operations with a custom backend.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the program cannot be initialized.
+    pub fn with_backend(program: Program, mut backend: B) -> Result<Self, Vec<Log>> {
+        let buffers = (program.buffer.parts.iter().enumerate())
+            .map(|(index, part)| {
+                let label = if index == 0 {
+                    "main".into()
+                } else {
+                    format!("main{index}")
+                };
+                backend.create_buffer(&label, BufferKind::Storage, part.size.into())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let input = Input::default();
+        let extern_buffers = ExternBuffers {
+            input: backend.create_buffer(
+                "input",
+                BufferKind::ReadOnlyStorage,
+                input.to_bytes().len() as u64,
+            )?,
+            time: backend.create_buffer("time", BufferKind::Uniform, TIME_BYTES)?,
+        };
+        let target_size_buffer =
+            backend.create_buffer("target_size", BufferKind::Uniform, TARGET_SIZE_BYTES)?;
+        let debug_buffer_size = debug::buffer_size(
+            program.asserts.len(),
+            program.debug_prints.len(),
+            program.debug_print_capacity,
+        );
+        let debug_buffer = (debug_buffer_size > 0)
+            .then(|| backend.create_buffer("debug", BufferKind::Storage, debug_buffer_size))
+            .transpose()?;
+        // the debug buffer is bound after all parts, or in place of the first part if there is none
+        let init_shader = buffers
+            .first()
+            .or(debug_buffer.as_ref())
+            .map(|first_buffer| {
+                let init_buffers: Vec<_> =
+                    [first_buffer, &extern_buffers.input, &extern_buffers.time]
+                        .into_iter()
+                        .chain(buffers.iter().skip(1))
+                        .chain(debug_buffer.as_ref().filter(|_| !buffers.is_empty()))
+                        .collect();
+                backend.create_compute_shader(
+                    &ShaderId::Init.to_string(),
+                    &program.init_shader,
+                    &program.init_source_map,
+                    &init_buffers,
+                )
+            })
+            .transpose()?;
+        let draw_buffers: Vec<_> = [
+            &target_size_buffer,
+            &extern_buffers.input,
+            &extern_buffers.time,
+        ]
+        .into_iter()
+        .chain(&buffers)
+        .collect();
+        let draw_shaders = program
+            .draw_shaders
+            .iter()
+            .zip(&program.draw_source_maps)
+            .enumerate()
+            .map(|(index, (code, source_map))| {
+                backend.create_draw_shader(
+                    &ShaderId::Draw(index).to_string(),
+                    code,
+                    source_map,
+                    &draw_buffers,
+                )
+            })
+            .collect::<Result<_, _>>()?;
+        let mut runner = Self {
             program,
-            device,
-            queue,
-            buffer,
+            backend,
+            buffers,
             init_shader,
-        })
+            is_init_done: false,
+            target_size_buffer,
+            draw_shaders,
+            input,
+            time: Time::default(),
+            extern_buffers,
+            debug_buffer,
+            debug_logs: vec![],
+        };
+        runner.write_target_size()?;
+        Ok(runner)
+    }
+
+    /// Returns the backend executing GPU operations.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns the storage buffers storing the values of all global variables.
+    ///
+    /// There is one buffer for each part of [`Runner::buffer_layout`], in the same order.
+    /// The location of each variable is given by the buffer layout.
+    pub fn buffers(&self) -> &[B::Buffer] {
+        &self.buffers
+    }
+
+    /// Returns the layout of the storage buffers.
+    pub fn buffer_layout(&self) -> &Buffer {
+        &self.program.buffer
     }
 
     /// Reads global variable value.
@@ -66,51 +333,309 @@ impl Runner {
     /// Variable `path` is the dot path of the module and the variable name separated by a `:`
     /// (e.g. `inner.module:my_buffer`).
     ///
-    /// If the buffer doesn't exist, an empty vector is returned.
+    /// If the variable doesn't exist, `None` is returned.
     pub fn read_variable(&self, path: &str) -> Option<GpuValue> {
-        if let Some(buffer) = self.buffer.as_ref()
-            && let Some(field) = self.program.buffer.fields.get(path)
-        {
-            let buffer = utils::read_buffer(
-                &self.device,
-                &self.queue,
-                buffer,
-                field.offset.into(),
-                field.size.into(),
-            );
-            Some(GpuValue::I32(i32::from_ne_bytes([
-                buffer[0], buffer[1], buffer[2], buffer[3],
-            ])))
+        let field = self.program.buffer.fields.get(path)?;
+        let (buffer_index, offset) = self.field_location(field)?;
+        let bytes =
+            self.backend
+                .read_buffer(&self.buffers[buffer_index], offset, field.size.into());
+        Some(GpuValue::from_bytes(field.type_, &bytes))
+    }
+
+    /// Reads the values of several global variables.
+    ///
+    /// Variable paths have the same format as for [`Runner::read_variable`]. All values are
+    /// retrieved from the GPU at once. Variables that don't exist are not included in the result.
+    pub fn read_variables(&self, paths: &[&str]) -> HashMap<String, GpuValue> {
+        let parts: Vec<_> = self
+            .buffers
+            .iter()
+            .zip(&self.program.buffer.parts)
+            .map(|(buffer, part)| (buffer, part.size.into()))
+            .collect();
+        let bytes = if parts.is_empty() {
+            vec![]
         } else {
-            None
+            self.backend.read_buffers(&parts)
+        };
+        snapshot::decode_fields(
+            &bytes,
+            fields::select(&self.program.buffer, paths).into_iter(),
+        )
+    }
+
+    /// Reads the values of all global variables.
+    ///
+    /// Keys of the returned map are the variable paths.
+    pub fn snapshot(&self) -> HashMap<String, GpuValue> {
+        self.read_variables(&fields::all_paths(&self.program.buffer))
+    }
+
+    /// Saves the values of all global variables in a file.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the file cannot be written.
+    pub fn save_state(&self, path: &Path) -> Result<(), Vec<Log>> {
+        State::new(self.snapshot()).save(path)
+    }
+
+    /// Restores the values of global variables from a file created with [`Runner::save_state`].
+    ///
+    /// Variable initialization is skipped, so variables missing from the file are set to zero.
+    /// As assertions and debug prints are only run during initialization, they are never run by
+    /// a runner whose state has been restored.
+    /// Saved variables that don't exist anymore or have a different type are ignored.
+    ///
+    /// Returned logs are warnings about the variables that cannot be restored.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the file cannot be read or is not a valid state file, or in
+    /// case a variable cannot be written.
+    pub fn load_state(&mut self, path: &Path) -> Result<Vec<Log>, Vec<Log>> {
+        let state = State::load(path)?;
+        let (variables, warnings) = state.restorable_variables(&self.program.buffer);
+        for (variable_path, value) in variables {
+            self.write_variable(&variable_path, value)?;
         }
+        self.is_init_done = true;
+        Ok(warnings)
+    }
+
+    /// Writes global variable value.
+    ///
+    /// Variable `path` has the same format as for [`Runner::read_variable`].
+    ///
+    /// The value is written immediately. Note that a value written before the first step is
+    /// overwritten by the variable initialization.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the variable doesn't exist or has a different type.
+    pub fn write_variable(&mut self, path: &str, value: GpuValue) -> Result<(), Vec<Log>> {
+        let field = fields::find_writable(&self.program.buffer, path, &value)?;
+        let Some((buffer_index, offset)) = self.field_location(field) else {
+            return Ok(()); // no-coverage (buffer always exists when there are variables)
+        };
+        self.backend
+            .write_buffer(&self.buffers[buffer_index], offset, &value.to_bytes())
+    }
+
+    /// Returns the size in pixels of the rendering target.
+    pub fn target_size(&self) -> (u32, u32) {
+        self.backend.target_size()
+    }
+
+    /// Resizes the rendering target.
+    ///
+    /// The size is clamped so that both dimensions are at least one pixel.
+    pub fn resize(&mut self, width: u32, height: u32) {
+        self.backend.resize_target(width, height);
+        #[expect(clippy::expect_used)] // target size buffer is always large enough
+        self.write_target_size()
+            .expect("internal error: cannot write target size");
+    }
+
+    /// Returns the input state used by the program.
+    pub fn input(&self) -> &Input {
+        &self.input
+    }
+
+    /// Returns a mutable reference to the input state used by the program.
+    ///
+    /// Changes are sent to the GPU at the beginning of the next step.
+    pub fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
+    }
+
+    /// Returns the index of the next step to run, starting from zero.
+    pub fn frame_index(&self) -> u32 {
+        self.time.frame_index
+    }
+
+    /// Sets a fixed time step, used to make runs reproducible.
+    ///
+    /// When set, `delta_seconds` is always equal to the fixed time step and `elapsed_seconds` only
+    /// depends on `frame_index`. Otherwise, both values are measured with the system clock.
+    pub fn set_fixed_timestep(&mut self, timestep: Option<Duration>) {
+        self.time.fixed_delta = timestep;
     }
 
     /// Runs a program step.
-    pub fn run_step(&mut self) {
-        let mut encoder = utils::create_encoder(&self.device);
-        if let Some(shader) = &mut self.init_shader
-            && !shader.is_init_done
-        {
-            let mut pass = utils::start_compute_pass(&mut encoder);
-            shader.run(&mut pass);
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the GPU reports an error while running the step, or in case
+    /// an assertion of the program has failed during the step.
+    ///
+    /// Assertions and debug prints are only run by the first step, which initializes global
+    /// variables. Records of these debug prints can then be retrieved with
+    /// [`Runner::take_debug_logs`].
+    pub fn run_step(&mut self) -> Result<(), Vec<Log>> {
+        let is_init_run = self.start_step()?;
+        if is_init_run && let Some(shader) = &self.init_shader {
+            self.backend.dispatch(shader)?;
+        }
+        self.finish_step(is_init_run);
+        if is_init_run {
+            self.read_init_debug_buffer()?;
         }
-        self.queue.submit(Some(encoder.finish()));
+        Ok(())
     }
-}
 
-/// A value retrieved from GPU.
-#[derive(Debug, PartialEq, Eq)]
-#[non_exhaustive]
-pub enum GpuValue {
-    /// An `i32` value.
-    I32(i32),
-}
+    /// Returns the logs of debug prints run since the last call, and removes them from the runner.
+    ///
+    /// Each log is located at the `debug_print` item at its origin. A warning is included in case
+    /// debug prints have been dropped because the debug buffer was full.
+    pub fn take_debug_logs(&mut self) -> Vec<Log> {
+        mem::take(&mut self.debug_logs)
+    }
+
+    /// Renders the program draw items on the rendering target.
+    ///
+    /// In case the rendering target is a window, the frame is presented on the window.
+    pub fn render(&mut self) {
+        self.backend.render(&self.draw_shaders);
+    }
+
+    /// Renders the program draw items and returns the rendered frame as RGBA bytes.
+    ///
+    /// Pixels are stored row by row, starting from the top-left corner, with 8 bits per channel.
+    ///
+    /// If the rendering target cannot be read (e.g. when the runner renders into a window), `None`
+    /// is returned.
+    pub fn capture_frame(&mut self) -> Option<Vec<u8>> {
+        self.render();
+        self.backend.read_target()
+    }
 
-impl Display for GpuValue {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::I32(value) => Display::fmt(value, f),
+    /// Renders the program draw items and saves the rendered frame as a PNG image.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the runner renders into a window, or in case the image cannot
+    /// be saved at the specified path.
+    pub fn save_frame(&mut self, path: &Path) -> Result<(), Vec<Log>> {
+        let (width, height) = self.backend.target_size();
+        let Some(frame) = self.capture_frame() else {
+            // coverage: off (difficult to test)
+            return Err(vec![Log {
+                level: LogLevel::Error,
+                message: "cannot capture a frame rendered into a window".into(),
+                location: None,
+                inner: vec![],
+            }]);
+            // coverage: on
+        };
+        image::save_png(path, width, height, &frame)
+    }
+
+    // returns the index of the buffer containing a field and the offset of the field in it
+    fn field_location(&self, field: &BufferField) -> Option<(usize, u64)> {
+        let part_index = self.program.buffer.part_index(field)?;
+        let part = &self.program.buffer.parts[part_index];
+        Some((part_index, (field.offset - part.offset).into()))
+    }
+
+    // writes extern variables and returns whether the init shader must be run during the step
+    fn start_step(&mut self) -> Result<bool, Vec<Log>> {
+        self.time.start_step();
+        self.backend
+            .write_buffer(&self.extern_buffers.input, 0, &self.input.to_bytes())?;
+        self.backend
+            .write_buffer(&self.extern_buffers.time, 0, &self.time.to_bytes())?;
+        Ok(self.init_shader.is_some() && !self.is_init_done)
+    }
+
+    fn finish_step(&mut self, is_init_run: bool) {
+        self.is_init_done |= is_init_run;
+        self.time.finish_step();
+    }
+
+    // debug items are only run by the init shader, so the debug buffer is written once and read
+    // once after init
+    fn read_init_debug_buffer(&mut self) -> Result<(), Vec<Log>> {
+        let Some(debug_buffer) = &self.debug_buffer else {
+            return Ok(());
+        };
+        let asserts = &self.program.asserts;
+        let prints = &self.program.debug_prints;
+        let print_capacity = self.program.debug_print_capacity;
+        let size = debug::buffer_size(asserts.len(), prints.len(), print_capacity);
+        let bytes = self.backend.read_buffer(debug_buffer, 0, size);
+        let (assert_bytes, print_bytes) = bytes.split_at(debug::assert_section_size(asserts.len()));
+        if !prints.is_empty() {
+            let (words, dropped_count) = debug::decode_print_words(print_bytes);
+            self.debug_logs
+                .extend(debug_logs::print_logs(prints, &words));
+            if dropped_count > 0 {
+                self.debug_logs
+                    .push(debug_logs::dropped_prints(dropped_count, print_capacity));
+            }
         }
+        let errors: Vec<_> = debug::decode_assert_failures(assert_bytes)
+            .into_iter()
+            .filter_map(|assert_id| asserts.get(assert_id).map(debug_logs::assert_failure))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn write_target_size(&mut self) -> Result<(), Vec<Log>> {
+        let (width, height) = self.backend.target_size();
+        let size = [width.to_ne_bytes(), height.to_ne_bytes()].concat();
+        self.backend
+            .write_buffer(&self.target_size_buffer, 0, &size)
+    }
+}
+
+impl<B: Backend> ProgramRunner for Runner<B> {
+    fn read_variable(&self, path: &str) -> Option<GpuValue> {
+        Self::read_variable(self, path)
+    }
+
+    fn read_variables(&self, paths: &[&str]) -> HashMap<String, GpuValue> {
+        Self::read_variables(self, paths)
+    }
+
+    fn snapshot(&self) -> HashMap<String, GpuValue> {
+        Self::snapshot(self)
+    }
+
+    fn write_variable(&mut self, path: &str, value: GpuValue) -> Result<(), Vec<Log>> {
+        Self::write_variable(self, path, value)
+    }
+
+    fn save_state(&self, path: &Path) -> Result<(), Vec<Log>> {
+        Self::save_state(self, path)
+    }
+
+    fn load_state(&mut self, path: &Path) -> Result<Vec<Log>, Vec<Log>> {
+        Self::load_state(self, path)
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        Self::input_mut(self)
+    }
+
+    fn frame_index(&self) -> u32 {
+        Self::frame_index(self)
+    }
+
+    fn set_fixed_timestep(&mut self, timestep: Option<Duration>) {
+        Self::set_fixed_timestep(self, timestep);
+    }
+
+    fn run_step(&mut self) -> Result<(), Vec<Log>> {
+        Self::run_step(self)
+    }
+
+    fn take_debug_logs(&mut self) -> Vec<Log> {
+        Self::take_debug_logs(self)
     }
 }
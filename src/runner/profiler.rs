@@ -0,0 +1,213 @@
+use crate::Log;
+use crate::runner::utils;
+use itertools::Itertools;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use wgpu::{
+    Buffer, BufferUsages, CommandEncoder, ComputePassTimestampWrites, Device, Features, QUERY_SIZE,
+    QuerySet, QuerySetDescriptor, QueryType, Queue,
+};
+
+// one timestamp at the beginning and one at the end of a pass
+const QUERY_COUNT: u32 = 2;
+
+/// The GPU timings of the shaders run by a [`WgpuBackend`](crate::WgpuBackend).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Profile {
+    /// The timed shader runs, in execution order.
+    pub samples: Vec<ProfileSample>,
+}
+
+impl Profile {
+    /// Returns the timing statistics of each shader, in order of first run.
+    pub fn shader_timings(&self) -> Vec<ShaderTimings> {
+        self.samples
+            .iter()
+            .map(|sample| &sample.shader)
+            .unique()
+            .map(|shader| {
+                let durations: Vec<_> = self
+                    .samples
+                    .iter()
+                    .filter(|sample| &sample.shader == shader)
+                    .map(|sample| sample.duration)
+                    .collect();
+                #[expect(clippy::cast_possible_truncation)] // number of runs is small
+                let count = durations.len() as u32;
+                ShaderTimings {
+                    shader: shader.clone(),
+                    count,
+                    min: durations.iter().copied().min().unwrap_or_default(),
+                    avg: durations.iter().sum::<Duration>() / count,
+                    max: durations.iter().copied().max().unwrap_or_default(),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the samples as a JSON trace readable by Chrome tracing tools (e.g. Perfetto).
+    pub fn to_chrome_trace(&self) -> String {
+        let trace = ChromeTrace {
+            trace_events: self
+                .samples
+                .iter()
+                .map(|sample| ChromeTraceEvent {
+                    name: &sample.shader,
+                    category: "gpu",
+                    phase: "X",
+                    timestamp: sample.start.as_secs_f64() * 1e6,
+                    duration: sample.duration.as_secs_f64() * 1e6,
+                    process_id: 0,
+                    thread_id: 0,
+                })
+                .collect(),
+            display_time_unit: "ns",
+        };
+        #[expect(clippy::unwrap_used)] // JSON serialization of the trace never fails
+        serde_json::to_string_pretty(&trace).unwrap()
+    }
+
+    /// Saves the samples as a JSON trace readable by Chrome tracing tools (e.g. Perfetto).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the file cannot be written.
+    pub fn save_chrome_trace(&self, path: &Path) -> Result<(), Vec<Log>> {
+        fs::write(path, self.to_chrome_trace())
+            .map_err(|error| vec![Log::from_io_error(error, path, "cannot write")])
+    }
+}
+
+/// A timed shader run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ProfileSample {
+    /// The shader label (e.g. `init shader`).
+    pub shader: String,
+    /// The start time of the run, relative to the start of the first timed run.
+    pub start: Duration,
+    /// The GPU duration of the run.
+    pub duration: Duration,
+}
+
+/// The timing statistics of a shader.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ShaderTimings {
+    /// The shader label (e.g. `init shader`).
+    pub shader: String,
+    /// The number of timed runs.
+    pub count: u32,
+    /// The minimum GPU duration of a run.
+    pub min: Duration,
+    /// The average GPU duration of a run.
+    pub avg: Duration,
+    /// The maximum GPU duration of a run.
+    pub max: Duration,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace<'profile> {
+    trace_events: Vec<ChromeTraceEvent<'profile>>,
+    display_time_unit: &'static str,
+}
+
+// times are in microseconds
+#[derive(Serialize)]
+struct ChromeTraceEvent<'profile> {
+    name: &'profile str,
+    #[serde(rename = "cat")]
+    category: &'static str,
+    #[serde(rename = "ph")]
+    phase: &'static str,
+    #[serde(rename = "ts")]
+    timestamp: f64,
+    #[serde(rename = "dur")]
+    duration: f64,
+    #[serde(rename = "pid")]
+    process_id: u32,
+    #[serde(rename = "tid")]
+    thread_id: u32,
+}
+
+// writes timestamps at the beginning and at the end of compute passes
+#[derive(Debug)]
+pub(crate) struct Profiler {
+    query_set: QuerySet,
+    resolve_buffer: Buffer,
+    tick_nanos: f64,
+    first_tick: Option<u64>,
+    profile: Profile,
+}
+
+impl Profiler {
+    // `None` is returned if the device doesn't support timestamp queries
+    pub(crate) fn new(device: &Device, queue: &Queue) -> Option<Self> {
+        device
+            .features()
+            .contains(Features::TIMESTAMP_QUERY)
+            .then(|| Self {
+                query_set: device.create_query_set(&QuerySetDescriptor {
+                    label: Some("gpex:query_set:timestamps"),
+                    ty: QueryType::Timestamp,
+                    count: QUERY_COUNT,
+                }),
+                resolve_buffer: utils::create_buffer(
+                    device,
+                    "gpex:buffer:timestamps",
+                    BufferUsages::QUERY_RESOLVE | BufferUsages::COPY_SRC,
+                    u64::from(QUERY_SIZE * QUERY_COUNT),
+                ),
+                tick_nanos: queue.get_timestamp_period().into(),
+                first_tick: None,
+                profile: Profile::default(),
+            })
+    }
+
+    pub(crate) fn profile(&self) -> &Profile {
+        &self.profile
+    }
+
+    pub(crate) fn timestamp_writes(&self) -> ComputePassTimestampWrites<'_> {
+        ComputePassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(0),
+            end_of_pass_write_index: Some(1),
+        }
+    }
+
+    pub(crate) fn resolve(&self, encoder: &mut CommandEncoder) {
+        encoder.resolve_query_set(&self.query_set, 0..QUERY_COUNT, &self.resolve_buffer, 0);
+    }
+
+    // reads the timestamps written by the last submitted pass
+    pub(crate) fn record(&mut self, device: &Device, queue: &Queue, shader: &str) {
+        let bytes = utils::read_buffer(
+            device,
+            queue,
+            &self.resolve_buffer,
+            0,
+            self.resolve_buffer.size(),
+        );
+        let mut ticks = bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_ne_bytes(chunk.try_into().unwrap_or_default()));
+        let start_tick = ticks.next().unwrap_or_default();
+        let end_tick = ticks.next().unwrap_or_default();
+        let first_tick = *self.first_tick.get_or_insert(start_tick);
+        self.profile.samples.push(ProfileSample {
+            shader: shader.into(),
+            start: self.ticks_to_duration(start_tick.saturating_sub(first_tick)),
+            duration: self.ticks_to_duration(end_tick.saturating_sub(start_tick)),
+        });
+    }
+
+    #[expect(clippy::cast_precision_loss)] // precision loss is negligible for timings
+    fn ticks_to_duration(&self, ticks: u64) -> Duration {
+        Duration::from_secs_f64(ticks as f64 * self.tick_nanos / 1e9)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::Log;
+use crate::runner::{GpuValue, Input};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// A backend running compiled `GPEx` programs.
+///
+/// It is implemented by [`Runner`](crate::Runner) on the GPU, and by
+/// [`Interpreter`](crate::Interpreter) on the CPU, so that the same code can run programs on
+/// both backends and compare their results.
+pub trait ProgramRunner {
+    /// Reads global variable value.
+    ///
+    /// Variable `path` is the dot path of the module and the variable name separated by a `:`
+    /// (e.g. `inner.module:my_buffer`).
+    fn read_variable(&self, path: &str) -> Option<GpuValue>;
+
+    /// Reads the values of several global variables.
+    ///
+    /// Variables that don't exist are not included in the result.
+    fn read_variables(&self, paths: &[&str]) -> HashMap<String, GpuValue>;
+
+    /// Reads the values of all global variables.
+    ///
+    /// Keys of the returned map are the variable paths.
+    fn snapshot(&self) -> HashMap<String, GpuValue>;
+
+    /// Writes global variable value.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the variable doesn't exist or has a different type.
+    fn write_variable(&mut self, path: &str, value: GpuValue) -> Result<(), Vec<Log>>;
+
+    /// Saves the values of all global variables in a file.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the file cannot be written.
+    fn save_state(&self, path: &Path) -> Result<(), Vec<Log>>;
+
+    /// Restores the values of global variables from a file created with
+    /// [`ProgramRunner::save_state`], and skips variable initialization.
+    ///
+    /// Assertions and debug prints, only run during initialization, are then skipped too.
+    ///
+    /// Returned logs are warnings about the variables that cannot be restored.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the file cannot be read or is not a valid state file, or in
+    /// case a variable cannot be written.
+    fn load_state(&mut self, path: &Path) -> Result<Vec<Log>, Vec<Log>>;
+
+    /// Returns a mutable reference to the input state used by the program.
+    fn input_mut(&mut self) -> &mut Input;
+
+    /// Returns the index of the next step to run, starting from zero.
+    fn frame_index(&self) -> u32;
+
+    /// Sets a fixed time step, used to make runs reproducible.
+    fn set_fixed_timestep(&mut self, timestep: Option<Duration>);
+
+    /// Runs a program step.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case the backend fails to run the step.
+    fn run_step(&mut self) -> Result<(), Vec<Log>>;
+
+    /// Returns the logs of debug prints run since the last call, and removes them from the runner.
+    fn take_debug_logs(&mut self) -> Vec<Log>;
+}
@@ -1,42 +1,140 @@
+use crate::Log;
+use crate::compiler::source_map::SourceMap;
+use crate::runner::errors::ErrorScope;
 use crate::runner::utils;
+use std::iter;
 use wgpu::{
-    BindGroup, BindGroupLayout, Buffer, ComputePass, ComputePipeline, Device, ShaderStages,
+    BindGroup, BindGroupLayout, Buffer, BufferBindingType, ComputePass, ComputePipeline, Device,
+    RenderPass, RenderPipeline, ShaderStages, TextureFormat,
 };
 
+const RECTANGLE_VERTEX_COUNT: u32 = 6;
+// next bindings are parts of the main buffer
+const COMPUTE_BINDING_TYPES: [BufferBindingType; 3] = [
+    BufferBindingType::Storage { read_only: false },
+    BufferBindingType::Storage { read_only: true },
+    BufferBindingType::Uniform,
+];
+const DRAW_BINDING_TYPES: [BufferBindingType; 3] = [
+    BufferBindingType::Uniform,
+    BufferBindingType::Storage { read_only: true },
+    BufferBindingType::Uniform,
+];
+
+/// A compute shader created by [`WgpuBackend`](crate::WgpuBackend).
 #[derive(Debug)]
-pub(crate) struct ComputeShader {
-    pub(crate) pipeline: ComputePipeline,
-    pub(crate) bind_group: BindGroup,
-    pub(crate) is_init_done: bool,
+pub struct WgpuComputeShader {
+    label: String,
+    pipeline: ComputePipeline,
+    bind_group: BindGroup,
 }
 
-impl ComputeShader {
-    pub(crate) fn new(device: &Device, buffer: &Buffer, code: &str) -> Self {
-        let layout = utils::create_bind_group_layout(device, ShaderStages::COMPUTE, 1);
-        let pipeline = utils::create_compute_pipeline(device, &layout, code);
-        let bind_group = Self::create_bind_group(device, &layout, buffer);
-        Self {
+impl WgpuComputeShader {
+    pub(crate) fn new(
+        device: &Device,
+        label: &str,
+        code: &str,
+        source_map: &SourceMap,
+        buffers: &[&Buffer],
+    ) -> Result<Self, Vec<Log>> {
+        let layout = utils::create_bind_group_layout(
+            device,
+            ShaderStages::COMPUTE,
+            &binding_types(
+                &COMPUTE_BINDING_TYPES,
+                BufferBindingType::Storage { read_only: false },
+                buffers.len(),
+            ),
+        );
+        let scope = ErrorScope::push(device);
+        let module = utils::create_shader_module(device, code);
+        let pipeline = utils::create_compute_pipeline(device, &layout, &module);
+        scope.pop_shader(&format!("{label} creation"), &module, code, source_map)?;
+        let bind_group = create_bind_group(device, &layout, buffers);
+        Ok(Self {
+            label: label.into(),
             pipeline,
             bind_group,
-            is_init_done: false,
-        }
+        })
     }
 
-    pub(crate) fn run(&mut self, pass: &mut ComputePass<'_>) {
+    pub(crate) fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub(crate) fn run(&self, pass: &mut ComputePass<'_>) {
         pass.set_pipeline(&self.pipeline);
         pass.set_bind_group(0, &self.bind_group, &[]);
         pass.dispatch_workgroups(1, 1, 1);
-        self.is_init_done = true;
     }
+}
 
-    fn create_bind_group(device: &Device, layout: &BindGroupLayout, buffer: &Buffer) -> BindGroup {
-        device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("gpex:bind_group"),
-            layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: buffer.as_entire_binding(),
-            }],
+/// A draw shader created by [`WgpuBackend`](crate::WgpuBackend).
+#[derive(Debug)]
+pub struct WgpuDrawShader {
+    pipeline: RenderPipeline,
+    bind_group: BindGroup,
+}
+
+impl WgpuDrawShader {
+    pub(crate) fn new(
+        device: &Device,
+        label: &str,
+        code: &str,
+        source_map: &SourceMap,
+        buffers: &[&Buffer],
+        format: TextureFormat,
+    ) -> Result<Self, Vec<Log>> {
+        let layout = utils::create_bind_group_layout(
+            device,
+            ShaderStages::VERTEX_FRAGMENT,
+            &binding_types(
+                &DRAW_BINDING_TYPES,
+                BufferBindingType::Storage { read_only: true },
+                buffers.len(),
+            ),
+        );
+        let scope = ErrorScope::push(device);
+        let module = utils::create_shader_module(device, code);
+        let pipeline = utils::create_render_pipeline(device, &layout, &module, format);
+        scope.pop_shader(&format!("{label} creation"), &module, code, source_map)?;
+        let bind_group = create_bind_group(device, &layout, buffers);
+        Ok(Self {
+            pipeline,
+            bind_group,
         })
     }
+
+    pub(crate) fn draw(&self, pass: &mut RenderPass<'_>) {
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &self.bind_group, &[]);
+        pass.draw(0..RECTANGLE_VERTEX_COUNT, 0..1);
+    }
+}
+
+fn binding_types(
+    first_types: &[BufferBindingType],
+    part_type: BufferBindingType,
+    count: usize,
+) -> Vec<BufferBindingType> {
+    first_types
+        .iter()
+        .copied()
+        .chain(iter::repeat(part_type))
+        .take(count)
+        .collect()
+}
+
+fn create_bind_group(device: &Device, layout: &BindGroupLayout, buffers: &[&Buffer]) -> BindGroup {
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("gpex:bind_group"),
+        layout,
+        entries: &(0..)
+            .zip(buffers)
+            .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                binding,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect::<Vec<_>>(),
+    })
 }
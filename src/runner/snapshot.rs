@@ -0,0 +1,57 @@
+use crate::compiler::transpilation::BufferField;
+use crate::runner::GpuValue;
+use crate::runner::utils;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use wgpu::{Buffer, Device};
+
+/// A variable snapshot requested with [`Runner::start_snapshot`](crate::Runner::start_snapshot)
+/// that may not be yet available.
+#[derive(Debug)]
+pub struct PendingSnapshot {
+    read: Option<(Buffer, Arc<AtomicBool>)>,
+    fields: Option<Vec<(String, BufferField)>>,
+    device: Device,
+}
+
+impl PendingSnapshot {
+    pub(crate) fn new(
+        read: Option<(Buffer, Arc<AtomicBool>)>,
+        fields: Vec<(String, BufferField)>,
+        device: Device,
+    ) -> Self {
+        Self {
+            read,
+            fields: Some(fields),
+            device,
+        }
+    }
+
+    /// Returns the variable values if they have been retrieved from the GPU, without blocking.
+    ///
+    /// Once the values have been returned, next calls return `None`.
+    pub fn try_finish(&mut self) -> Option<HashMap<String, GpuValue>> {
+        self.fields.as_ref()?;
+        let bytes = if let Some((read_buffer, is_mapped)) = &self.read {
+            utils::try_finish_buffer_read(&self.device, read_buffer, is_mapped)?
+        } else {
+            vec![]
+        };
+        self.read = None;
+        Some(decode_fields(&bytes, self.fields.take()?.into_iter()))
+    }
+}
+
+pub(crate) fn decode_fields(
+    bytes: &[u8],
+    fields: impl Iterator<Item = (String, BufferField)>,
+) -> HashMap<String, GpuValue> {
+    fields
+        .map(|(path, field)| {
+            let offset = field.offset as usize;
+            let value = GpuValue::from_bytes(field.type_, &bytes[offset..]);
+            (path, value)
+        })
+        .collect()
+}
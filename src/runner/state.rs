@@ -0,0 +1,121 @@
+use crate::compiler::transpilation::Buffer;
+use crate::runner::GpuValue;
+use crate::{Log, LogInner, LogLevel};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct State {
+    variables: BTreeMap<String, StateValue>,
+}
+
+// `f32` values are saved as bit patterns, as JSON cannot represent NaN and infinite values
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+enum StateValue {
+    I32(i32),
+    F32Bits(u32),
+}
+
+impl From<GpuValue> for StateValue {
+    fn from(value: GpuValue) -> Self {
+        match value {
+            GpuValue::I32(value) => Self::I32(value),
+            GpuValue::F32(value) => Self::F32Bits(value.to_bits()),
+        }
+    }
+}
+
+impl From<StateValue> for GpuValue {
+    fn from(value: StateValue) -> Self {
+        match value {
+            StateValue::I32(value) => Self::I32(value),
+            StateValue::F32Bits(bits) => Self::F32(f32::from_bits(bits)),
+        }
+    }
+}
+
+impl State {
+    pub(crate) fn new(variables: HashMap<String, GpuValue>) -> Self {
+        Self {
+            variables: variables
+                .into_iter()
+                .map(|(path, value)| (path, value.into()))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> Result<(), Vec<Log>> {
+        #[expect(clippy::unwrap_used)] // maps with string keys and integer values are valid JSON
+        let serialized = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, serialized)
+            .map_err(|error| vec![Log::from_io_error(error, path, "cannot write")])
+    }
+
+    pub(crate) fn load(path: &Path) -> Result<Self, Vec<Log>> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| vec![Log::from_io_error(error, path, "cannot read")])?;
+        serde_json::from_str(&content).map_err(|error| {
+            vec![Log {
+                level: LogLevel::Error,
+                message: format!("invalid state file \"{}\": {error}", path.display()),
+                location: None,
+                inner: vec![],
+            }]
+        })
+    }
+
+    // returns the variables that can be restored, and warnings for the other variables
+    pub(crate) fn restorable_variables(
+        self,
+        buffer: &Buffer,
+    ) -> (Vec<(String, GpuValue)>, Vec<Log>) {
+        let mut warnings = vec![];
+        for variable_path in buffer.fields.keys().sorted_unstable() {
+            if !self.variables.contains_key(variable_path) {
+                warnings.push(Log {
+                    level: LogLevel::Warning,
+                    message: format!("`{variable_path}` variable not found in state"),
+                    location: None,
+                    inner: vec![LogInner {
+                        level: LogLevel::Info,
+                        message: "variable is set to zero".into(),
+                        location: None,
+                    }],
+                });
+            }
+        }
+        let mut restorable_variables = vec![];
+        for (variable_path, value) in self.variables {
+            let value = GpuValue::from(value);
+            match buffer.fields.get(&variable_path) {
+                Some(field) if field.type_ == value.type_() => {
+                    restorable_variables.push((variable_path, value));
+                }
+                Some(field) => warnings.push(Log {
+                    level: LogLevel::Warning,
+                    message: format!("`{variable_path}` variable not restored from state"),
+                    location: None,
+                    inner: vec![LogInner {
+                        level: LogLevel::Info,
+                        message: format!(
+                            "saved value has type `{}`, but variable has type `{}`",
+                            value.type_(),
+                            field.type_
+                        ),
+                        location: None,
+                    }],
+                }),
+                None => warnings.push(Log {
+                    level: LogLevel::Warning,
+                    message: format!("`{variable_path}` variable in state not found in program"),
+                    location: None,
+                    inner: vec![],
+                }),
+            }
+        }
+        (restorable_variables, warnings)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::runner::utils;
+use crate::{Log, LogLevel};
+use wgpu::{
+    Adapter, Device, Surface, SurfaceConfiguration, SurfaceError, SurfaceTexture, Texture,
+    TextureFormat, TextureView,
+};
+
+pub(crate) const DEFAULT_TARGET_SIZE: (u32, u32) = (800, 600);
+
+#[derive(Debug)]
+pub(crate) enum Target {
+    Texture(Texture),
+    Surface(Surface<'static>, SurfaceConfiguration),
+}
+
+impl Target {
+    pub(crate) fn new_texture(device: &Device, (width, height): (u32, u32)) -> Self {
+        Self::Texture(utils::create_target_texture(device, width, height))
+    }
+
+    pub(crate) fn new_surface(
+        surface: Surface<'static>,
+        adapter: &Adapter,
+        device: &Device,
+        (width, height): (u32, u32),
+    ) -> Result<Self, Vec<Log>> {
+        let Some(mut config) = surface.get_default_config(adapter, width.max(1), height.max(1))
+        else {
+            // coverage: off (difficult to test)
+            return Err(vec![Log {
+                level: LogLevel::Error,
+                message: "window surface not supported by the graphic adapter".into(),
+                location: None,
+                inner: vec![],
+            }]);
+            // coverage: on
+        };
+        let capabilities = surface.get_capabilities(adapter);
+        if let Some(&format) = capabilities.formats.iter().find(|format| !format.is_srgb()) {
+            config.format = format;
+        }
+        surface.configure(device, &config);
+        Ok(Self::Surface(surface, config))
+    }
+
+    pub(crate) fn size(&self) -> (u32, u32) {
+        match self {
+            Self::Texture(texture) => (texture.width(), texture.height()),
+            Self::Surface(_, config) => (config.width, config.height),
+        }
+    }
+
+    pub(crate) fn texture(&self) -> Option<&Texture> {
+        match self {
+            Self::Texture(texture) => Some(texture),
+            Self::Surface(_, _) => None, // no-coverage (difficult to test)
+        }
+    }
+
+    pub(crate) fn format(&self) -> TextureFormat {
+        match self {
+            Self::Texture(texture) => texture.format(),
+            Self::Surface(_, config) => config.format,
+        }
+    }
+
+    pub(crate) fn resize(&mut self, device: &Device, (width, height): (u32, u32)) {
+        let (width, height) = (width.max(1), height.max(1));
+        match self {
+            Self::Texture(texture) => {
+                *texture = utils::create_target_texture(device, width, height);
+            }
+            Self::Surface(surface, config) => {
+                config.width = width;
+                config.height = height;
+                surface.configure(device, config);
+            }
+        }
+    }
+
+    // `None` is returned if the frame cannot be rendered on the surface (e.g. window minimized)
+    pub(crate) fn frame(&self, device: &Device) -> Option<TargetFrame> {
+        match self {
+            Self::Texture(texture) => Some(TargetFrame {
+                view: texture.create_view(&wgpu::TextureViewDescriptor::default()),
+                surface_texture: None,
+            }),
+            Self::Surface(surface, config) => match surface.get_current_texture() {
+                Ok(texture) => Some(TargetFrame {
+                    view: texture
+                        .texture
+                        .create_view(&wgpu::TextureViewDescriptor::default()),
+                    surface_texture: Some(texture),
+                }),
+                // coverage: off (difficult to test)
+                Err(SurfaceError::Outdated | SurfaceError::Lost) => {
+                    surface.configure(device, config);
+                    None
+                }
+                Err(_) => None,
+                // coverage: on
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct TargetFrame {
+    pub(crate) view: TextureView,
+    surface_texture: Option<SurfaceTexture>,
+}
+
+impl TargetFrame {
+    pub(crate) fn present(self) {
+        if let Some(surface_texture) = self.surface_texture {
+            surface_texture.present();
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::compiler::EXTENSION;
+use crate::compiler::transpilation::Program;
+use crate::runner::ProgramRunner;
+use crate::utils::reading;
+use crate::{CompileOptions, Log, LogInner, LogLevel, LogLocation};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+const PUBLIC_PREFIX: &str = "pub ";
+const VARIABLE_PREFIX: &str = "var ";
+const EXPECTED_PREFIX: &str = "// expected:";
+
+/// A set of tests defined in a `GPEx` project.
+///
+/// Each global variable followed on the same line by an `// expected: <value>` comment is a test
+/// checking the value of the variable once the program has run. Modules containing at least one
+/// of these comments are test modules.
+///
+/// Assertions of the program are also checked, and their failures are reported as errors
+/// interrupting the run. Logs of debug prints are collected in the report.
+#[derive(Debug)]
+pub struct TestSuite {
+    program: Program,
+    expectations: Vec<Expectation>,
+}
+
+impl TestSuite {
+    /// Compiles a `GPEx` project folder with the specified options and finds its tests.
+    ///
+    /// Compilation warnings are returned with the test suite.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in case compilation fails.
+    pub fn load(root_path: &Path, options: CompileOptions) -> Result<(Self, Vec<Log>), Vec<Log>> {
+        let (program, warnings) = crate::compile_with_options(root_path, options)?;
+        let expectations = reading::read(root_path, root_path, EXTENSION)?
+            .iter()
+            .flat_map(|file| expectations(&file.content, &file.fs_path, &file.dot_path))
+            .collect();
+        Ok((
+            Self {
+                program,
+                expectations,
+            },
+            warnings,
+        ))
+    }
+
+    /// Returns the compiled program to run.
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Returns the number of tests.
+    pub fn len(&self) -> usize {
+        self.expectations.len()
+    }
+
+    /// Returns whether the project contains no test.
+    pub fn is_empty(&self) -> bool {
+        self.expectations.is_empty()
+    }
+
+    /// Runs `steps` program steps with `runner`, and checks the tests.
+    ///
+    /// `runner` should run the program returned by [`TestSuite::program`].
+    /// In case a step fails, the remaining steps are not run, and the errors are stored in the
+    /// report.
+    pub fn run(&self, runner: &mut impl ProgramRunner, steps: u32) -> TestReport {
+        let mut errors = vec![];
+        let mut debug_logs = vec![];
+        for _ in 0..steps {
+            let result = runner.run_step();
+            debug_logs.extend(runner.take_debug_logs());
+            if let Err(step_errors) = result {
+                errors = step_errors;
+                break;
+            }
+        }
+        let paths: Vec<_> = self
+            .expectations
+            .iter()
+            .map(|expectation| expectation.variable_path.as_str())
+            .collect();
+        let values = runner.read_variables(&paths);
+        TestReport {
+            results: self
+                .expectations
+                .iter()
+                .map(|expectation| TestResult {
+                    variable_path: expectation.variable_path.clone(),
+                    expected: expectation.value.clone(),
+                    actual: values
+                        .get(&expectation.variable_path)
+                        .map(ToString::to_string),
+                    path: expectation.path.clone(),
+                    code: Arc::clone(&expectation.code),
+                    span_start: expectation.span_start,
+                    span_end: expectation.span_end,
+                })
+                .collect(),
+            errors,
+            debug_logs,
+        }
+    }
+}
+
+/// The results of a [`TestSuite`] run.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TestReport {
+    /// The result of each test, in source order.
+    pub results: Vec<TestResult>,
+    /// The errors that have interrupted the run.
+    pub errors: Vec<Log>,
+    /// The logs of debug prints run during the run.
+    pub debug_logs: Vec<Log>,
+}
+
+impl TestReport {
+    /// Returns whether all tests have passed and the run has not been interrupted.
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty() && self.results.iter().all(TestResult::is_success)
+    }
+
+    /// Returns the number of failed tests.
+    pub fn failure_count(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| !result.is_success())
+            .count()
+    }
+}
+
+/// The result of a test checking the value of a global variable.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TestResult {
+    /// The path of the checked variable (e.g. `inner.module:my_variable`).
+    pub variable_path: String,
+    /// The expected value, as written in the source code.
+    pub expected: String,
+    /// The actual value, or `None` if the variable doesn't exist.
+    pub actual: Option<String>,
+    path: PathBuf,
+    code: Arc<str>,
+    span_start: usize,
+    span_end: usize,
+}
+
+impl TestResult {
+    /// Returns whether the variable has the expected value.
+    pub fn is_success(&self) -> bool {
+        self.actual.as_ref() == Some(&self.expected)
+    }
+
+    /// Returns the location of the checked variable in the source code.
+    pub fn location(&self) -> LogLocation {
+        LogLocation {
+            path: self.path.clone(),
+            code: self.code.to_string(),
+            span: self.span_start..self.span_end,
+        }
+    }
+
+    /// Converts the result to a log, with an error level if the test has failed.
+    pub fn to_log(&self) -> Log {
+        if self.is_success() {
+            Log {
+                level: LogLevel::Info,
+                message: format!("`{}` test passed", self.variable_path),
+                location: Some(self.location()),
+                inner: vec![],
+            }
+        } else {
+            Log {
+                level: LogLevel::Error,
+                message: format!("`{}` test failed", self.variable_path),
+                location: Some(self.location()),
+                inner: vec![
+                    LogInner {
+                        level: LogLevel::Info,
+                        message: format!("expected value: `{}`", self.expected),
+                        location: None,
+                    },
+                    LogInner {
+                        level: LogLevel::Info,
+                        message: if let Some(actual) = &self.actual {
+                            format!("actual value: `{actual}`")
+                        } else {
+                            "variable not found".into()
+                        },
+                        location: None,
+                    },
+                ],
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Expectation {
+    variable_path: String,
+    value: String,
+    path: PathBuf,
+    // shared by all expectations of the same file
+    code: Arc<str>,
+    span_start: usize,
+    span_end: usize,
+}
+
+// finds the lines with the format `[pub] var <name> = ... // expected: <value>`
+fn expectations(code: &str, path: &Path, dot_path: &str) -> Vec<Expectation> {
+    let mut expectations = vec![];
+    let mut line_offset = 0;
+    let shared_code: Arc<str> = code.into();
+    for line in code.split_inclusive('\n') {
+        let trimmed_line = line.trim_start();
+        let trimmed_line = trimmed_line
+            .strip_prefix(PUBLIC_PREFIX)
+            .unwrap_or(trimmed_line);
+        if let Some(definition) = trimmed_line.strip_prefix(VARIABLE_PREFIX)
+            && let Some((_, value)) = line.split_once(EXPECTED_PREFIX)
+        {
+            let name_length = definition
+                .find(|char: char| !char.is_alphanumeric() && char != '_')
+                .unwrap_or(definition.len());
+            let span_start = line_offset + line.len() - definition.len();
+            expectations.push(Expectation {
+                variable_path: format!("{dot_path}:{}", &definition[..name_length]),
+                value: value.trim().into(),
+                path: path.into(),
+                code: Arc::clone(&shared_code),
+                span_start,
+                span_end: span_start + name_length,
+            });
+        }
+        line_offset += line.len();
+    }
+    expectations
+}
@@ -0,0 +1,44 @@
+use std::time::{Duration, Instant};
+
+pub(crate) const TIME_BYTES: u64 = 16;
+
+// time values exposed to the program as extern variables
+#[derive(Debug, Default)]
+pub(crate) struct Time {
+    pub(crate) frame_index: u32,
+    pub(crate) fixed_delta: Option<Duration>,
+    first_step_instant: Option<Instant>,
+    last_step_instant: Option<Instant>,
+    delta_seconds: f32,
+    elapsed_seconds: f32,
+}
+
+impl Time {
+    pub(crate) fn start_step(&mut self) {
+        if let Some(fixed_delta) = self.fixed_delta {
+            self.delta_seconds = fixed_delta.as_secs_f32();
+            self.elapsed_seconds = fixed_delta.saturating_mul(self.frame_index).as_secs_f32();
+        } else {
+            let now = Instant::now();
+            let first_step_instant = *self.first_step_instant.get_or_insert(now);
+            let last_step_instant = self.last_step_instant.replace(now).unwrap_or(now);
+            self.delta_seconds = (now - last_step_instant).as_secs_f32();
+            self.elapsed_seconds = (now - first_step_instant).as_secs_f32();
+        }
+    }
+
+    pub(crate) fn finish_step(&mut self) {
+        self.frame_index += 1;
+    }
+
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        self.frame_index
+            .cast_signed()
+            .to_ne_bytes()
+            .into_iter()
+            .chain(self.delta_seconds.to_ne_bytes())
+            .chain(self.elapsed_seconds.to_ne_bytes())
+            .chain([0; 4]) // padding
+            .collect()
+    }
+}
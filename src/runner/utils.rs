@@ -1,46 +1,48 @@
-use crate::{Log, LogLevel};
+use crate::{Log, LogInner, LogLevel, Program, TargetLimits};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use wgpu::{
-    Adapter, BackendOptions, Backends, BindGroupLayout, BindGroupLayoutEntry, BindingType, Buffer,
-    BufferBindingType, BufferDescriptor, BufferUsages, CommandEncoder, CommandEncoderDescriptor,
-    ComputePass, ComputePassDescriptor, ComputePipeline, ComputePipelineDescriptor, Device,
-    DeviceDescriptor, ExperimentalFeatures, Features, Instance, InstanceFlags, Limits, MapMode,
-    MemoryBudgetThresholds, MemoryHints, PipelineCompilationOptions, PipelineLayoutDescriptor,
-    PollType, PowerPreference, Queue, RequestAdapterOptions, ShaderModuleDescriptor, ShaderStages,
-    Trace,
+    Adapter, BackendOptions, Backends, BindGroupLayout, BindGroupLayoutEntry, BindingType,
+    BlendState, Buffer, BufferBindingType, BufferDescriptor, BufferUsages,
+    COPY_BYTES_PER_ROW_ALIGNMENT, Color, ColorTargetState, ColorWrites, CommandEncoder,
+    CommandEncoderDescriptor, ComputePass, ComputePassDescriptor, ComputePassTimestampWrites,
+    ComputePipeline, ComputePipelineDescriptor, Device, DeviceDescriptor, ExperimentalFeatures,
+    Extent3d, Features, FragmentState, Instance, InstanceFlags, LoadOp, MapMode,
+    MemoryBudgetThresholds, MemoryHints, MultisampleState, Operations, PipelineCompilationOptions,
+    PipelineLayoutDescriptor, PollType, PrimitiveState, Queue, RenderPass,
+    RenderPassColorAttachment, RenderPassDescriptor, RenderPipeline, RenderPipelineDescriptor,
+    ShaderModule, ShaderModuleDescriptor, ShaderStages, StoreOp, SubmissionIndex,
+    TexelCopyBufferInfo, TexelCopyBufferLayout, Texture, TextureDescriptor, TextureDimension,
+    TextureFormat, TextureUsages, TextureView, Trace, VertexState,
 };
 
-pub(crate) fn create_instance() -> Instance {
+pub(crate) const TARGET_TEXTURE_FORMAT: TextureFormat = TextureFormat::Rgba8Unorm;
+const TARGET_TEXTURE_PIXEL_BYTES: u32 = 4;
+
+pub(crate) fn create_instance(backends: Backends) -> Instance {
     Instance::new(&wgpu::InstanceDescriptor {
-        backends: Backends::from_env().unwrap_or_else(Backends::all),
+        backends,
         flags: InstanceFlags::default(),
         memory_budget_thresholds: MemoryBudgetThresholds::default(),
         backend_options: BackendOptions::default(),
     })
 }
 
-pub(crate) async fn create_adapter(instance: &Instance) -> Result<Adapter, Vec<Log>> {
-    let options = RequestAdapterOptions {
-        power_preference: PowerPreference::default(),
-        force_fallback_adapter: false,
-        compatible_surface: None,
+// timestamp queries are enabled only if requested and supported by the adapter, and the device
+// supports the best limits of the adapter
+pub(crate) async fn create_device(
+    adapter: &Adapter,
+    is_profiling_enabled: bool,
+) -> Result<(Device, Queue), Vec<Log>> {
+    let required_features = if is_profiling_enabled {
+        adapter.features() & Features::TIMESTAMP_QUERY
+    } else {
+        Features::default()
     };
-    instance.request_adapter(&options).await.map_err(|error| {
-        // coverage: off (difficult to test)
-        vec![Log {
-            level: LogLevel::Error,
-            message: format!("no supported graphic adapter found: {error}"),
-            location: None,
-            inner: vec![],
-        }]
-    })
-    // coverage: on
-}
-
-pub(crate) async fn create_device(adapter: &Adapter) -> Result<(Device, Queue), Vec<Log>> {
     let descriptor = DeviceDescriptor {
         label: Some("gpex:device"),
-        required_features: Features::default(),
-        required_limits: Limits::default(),
+        required_features,
+        required_limits: adapter.limits(),
         experimental_features: ExperimentalFeatures::default(),
         memory_hints: MemoryHints::Performance,
         trace: Trace::Off,
@@ -57,35 +59,57 @@ pub(crate) async fn create_device(adapter: &Adapter) -> Result<(Device, Queue),
     // coverage: on
 }
 
-pub(crate) fn create_buffer(device: &Device, label: &str, size: u64) -> Option<Buffer> {
-    (size > 0).then(|| {
-        device.create_buffer(&BufferDescriptor {
-            label: Some(label),
-            size,
-            usage: BufferUsages::STORAGE
-                | BufferUsages::COPY_SRC
-                | BufferUsages::COPY_DST
-                | BufferUsages::UNIFORM
-                | BufferUsages::VERTEX
-                | BufferUsages::INDEX,
-            mapped_at_creation: false,
-        })
+pub(crate) fn check_limits(device: &Device, program: &Program) -> Result<(), Vec<Log>> {
+    let required_limits = TargetLimits::required_by(program);
+    let unsupported = TargetLimits::from(&device.limits()).unsupported(required_limits);
+    if unsupported.is_empty() {
+        return Ok(());
+    }
+    Err(vec![Log {
+        level: LogLevel::Error,
+        message: "graphic device doesn't support the limits required by the program".into(),
+        location: None,
+        inner: unsupported
+            .into_iter()
+            .map(|(name, limit, required_limit)| LogInner {
+                level: LogLevel::Info,
+                message: format!(
+                    "`{name}` is {limit} on the device, but {required_limit} is required"
+                ),
+                location: None,
+            })
+            .collect(),
+    }])
+}
+
+pub(crate) fn create_buffer(
+    device: &Device,
+    label: &str,
+    usage: BufferUsages,
+    size: u64,
+) -> Buffer {
+    device.create_buffer(&BufferDescriptor {
+        label: Some(label),
+        size,
+        usage,
+        mapped_at_creation: false,
     })
 }
 
 pub(crate) fn create_bind_group_layout(
     device: &Device,
     stages: ShaderStages,
-    storage_count: u32,
+    binding_types: &[BufferBindingType],
 ) -> BindGroupLayout {
     device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
         label: Some("gpex:bind_group_layout"),
-        entries: &(0..storage_count)
-            .map(|binding| BindGroupLayoutEntry {
+        entries: &(0..)
+            .zip(binding_types)
+            .map(|(binding, &binding_type)| BindGroupLayoutEntry {
                 binding,
                 visibility: stages,
                 ty: BindingType::Buffer {
-                    ty: BufferBindingType::Storage { read_only: false },
+                    ty: binding_type,
                     has_dynamic_offset: false,
                     min_binding_size: None,
                 },
@@ -101,17 +125,103 @@ pub(crate) fn create_encoder(device: &Device) -> CommandEncoder {
     })
 }
 
-pub(crate) fn start_compute_pass(encoder: &mut CommandEncoder) -> ComputePass<'_> {
+pub(crate) fn start_compute_pass<'encoder>(
+    encoder: &'encoder mut CommandEncoder,
+    timestamp_writes: Option<ComputePassTimestampWrites<'_>>,
+) -> ComputePass<'encoder> {
     encoder.begin_compute_pass(&ComputePassDescriptor {
         label: Some("gpex:compute_pass"),
+        timestamp_writes,
+    })
+}
+
+pub(crate) fn create_target_texture(device: &Device, width: u32, height: u32) -> Texture {
+    device.create_texture(&TextureDescriptor {
+        label: Some("gpex:texture:target"),
+        size: Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format: TARGET_TEXTURE_FORMAT,
+        usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+        view_formats: &[],
+    })
+}
+
+pub(crate) fn start_render_pass<'encoder>(
+    encoder: &'encoder mut CommandEncoder,
+    view: &TextureView,
+) -> RenderPass<'encoder> {
+    encoder.begin_render_pass(&RenderPassDescriptor {
+        label: Some("gpex:render_pass"),
+        color_attachments: &[Some(RenderPassColorAttachment {
+            view,
+            depth_slice: None,
+            resolve_target: None,
+            ops: Operations {
+                load: LoadOp::Clear(Color::BLACK),
+                store: StoreOp::Store,
+            },
+        })],
+        depth_stencil_attachment: None,
         timestamp_writes: None,
+        occlusion_query_set: None,
+        multiview_mask: None,
+    })
+}
+
+pub(crate) fn create_shader_module(device: &Device, code: &str) -> ShaderModule {
+    device.create_shader_module(ShaderModuleDescriptor {
+        label: Some("gpex:shader_module"),
+        source: wgpu::ShaderSource::Wgsl(code.into()),
+    })
+}
+
+pub(crate) fn create_render_pipeline(
+    device: &Device,
+    layout: &BindGroupLayout,
+    module: &ShaderModule,
+    format: TextureFormat,
+) -> RenderPipeline {
+    device.create_render_pipeline(&RenderPipelineDescriptor {
+        label: Some("gpex:render_pipeline"),
+        layout: Some(&device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("gpex:render_pipeline_layout"),
+            bind_group_layouts: &[layout],
+            immediate_size: 0,
+        })),
+        vertex: VertexState {
+            module,
+            entry_point: None,
+            compilation_options: PipelineCompilationOptions::default(),
+            buffers: &[],
+        },
+        primitive: PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: MultisampleState::default(),
+        fragment: Some(FragmentState {
+            module,
+            entry_point: None,
+            compilation_options: PipelineCompilationOptions::default(),
+            targets: &[Some(ColorTargetState {
+                format,
+                blend: Some(BlendState::ALPHA_BLENDING),
+                write_mask: ColorWrites::ALL,
+            })],
+        }),
+        multiview_mask: None,
+        cache: None,
     })
 }
 
 pub(crate) fn create_compute_pipeline(
     device: &Device,
     layout: &BindGroupLayout,
-    code: &str,
+    module: &ShaderModule,
 ) -> ComputePipeline {
     device.create_compute_pipeline(&ComputePipelineDescriptor {
         label: Some("gpex:compute_pipeline"),
@@ -120,10 +230,7 @@ pub(crate) fn create_compute_pipeline(
             bind_group_layouts: &[layout],
             immediate_size: 0,
         })),
-        module: &device.create_shader_module(ShaderModuleDescriptor {
-            label: Some("gpex:shader_module"),
-            source: wgpu::ShaderSource::Wgsl(code.into()),
-        }),
+        module,
         entry_point: None,
         compilation_options: PipelineCompilationOptions::default(),
         cache: None,
@@ -137,17 +244,119 @@ pub(crate) fn read_buffer(
     offset: u64,
     size: u64,
 ) -> Vec<u8> {
-    let read_buffer = device.create_buffer(&BufferDescriptor {
+    let (read_buffer, submission_index) = copy_to_read_buffer(device, queue, buffer, offset, size);
+    map_read_buffer(device, &read_buffer, submission_index)
+}
+
+// buffers are read with their size, and their content is concatenated
+pub(crate) fn read_buffers(device: &Device, queue: &Queue, buffers: &[(&Buffer, u64)]) -> Vec<u8> {
+    let (read_buffer, submission_index) = copy_to_shared_read_buffer(device, queue, buffers);
+    map_read_buffer(device, &read_buffer, submission_index)
+}
+
+// buffers are read with their size, and their content is concatenated
+pub(crate) fn start_buffer_read(
+    device: &Device,
+    queue: &Queue,
+    buffers: &[(&Buffer, u64)],
+) -> (Buffer, Arc<AtomicBool>) {
+    let (read_buffer, _) = copy_to_shared_read_buffer(device, queue, buffers);
+    let is_mapped = Arc::new(AtomicBool::new(false));
+    let is_mapped_clone = is_mapped.clone();
+    read_buffer
+        .slice(..)
+        .map_async(MapMode::Read, move |result| {
+            is_mapped_clone.store(result.is_ok(), Ordering::Release);
+        });
+    (read_buffer, is_mapped)
+}
+
+// `None` is returned if the buffer is not yet mapped
+pub(crate) fn try_finish_buffer_read(
+    device: &Device,
+    read_buffer: &Buffer,
+    is_mapped: &AtomicBool,
+) -> Option<Vec<u8>> {
+    #[expect(clippy::expect_used)] // should never happen
+    device
+        .poll(PollType::Poll)
+        .expect("internal error: cannot read buffer");
+    is_mapped
+        .load(Ordering::Acquire)
+        .then(|| unmap_read_buffer(read_buffer))
+}
+
+pub(crate) fn read_texture(device: &Device, queue: &Queue, texture: &Texture) -> Vec<u8> {
+    let row_bytes = texture.width() * TARGET_TEXTURE_PIXEL_BYTES;
+    let padded_row_bytes = row_bytes.next_multiple_of(COPY_BYTES_PER_ROW_ALIGNMENT);
+    let read_buffer = create_read_buffer(device, u64::from(padded_row_bytes * texture.height()));
+    let mut encoder = create_encoder(device);
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        TexelCopyBufferInfo {
+            buffer: &read_buffer,
+            layout: TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_row_bytes),
+                rows_per_image: None,
+            },
+        },
+        texture.size(),
+    );
+    let submission_index = queue.submit(Some(encoder.finish()));
+    map_read_buffer(device, &read_buffer, submission_index)
+        .chunks(padded_row_bytes as usize)
+        .flat_map(|row| &row[..row_bytes as usize])
+        .copied()
+        .collect()
+}
+
+fn create_read_buffer(device: &Device, size: u64) -> Buffer {
+    device.create_buffer(&BufferDescriptor {
         label: Some("gpex:buffer:storage_read"),
         size,
         usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
         mapped_at_creation: false,
-    });
+    })
+}
+
+fn copy_to_read_buffer(
+    device: &Device,
+    queue: &Queue,
+    buffer: &Buffer,
+    offset: u64,
+    size: u64,
+) -> (Buffer, SubmissionIndex) {
+    let read_buffer = create_read_buffer(device, size);
     let mut encoder = create_encoder(device);
     encoder.copy_buffer_to_buffer(buffer, offset, &read_buffer, 0, Some(size));
     let submission_index = queue.submit(Some(encoder.finish()));
-    let slice = read_buffer.slice(..);
-    slice.map_async(MapMode::Read, |_| ());
+    (read_buffer, submission_index)
+}
+
+fn copy_to_shared_read_buffer(
+    device: &Device,
+    queue: &Queue,
+    buffers: &[(&Buffer, u64)],
+) -> (Buffer, SubmissionIndex) {
+    let size = buffers.iter().map(|(_, size)| size).sum();
+    let read_buffer = create_read_buffer(device, size);
+    let mut encoder = create_encoder(device);
+    let mut offset = 0;
+    for &(buffer, size) in buffers {
+        encoder.copy_buffer_to_buffer(buffer, 0, &read_buffer, offset, Some(size));
+        offset += size;
+    }
+    let submission_index = queue.submit(Some(encoder.finish()));
+    (read_buffer, submission_index)
+}
+
+fn map_read_buffer(
+    device: &Device,
+    read_buffer: &Buffer,
+    submission_index: SubmissionIndex,
+) -> Vec<u8> {
+    read_buffer.slice(..).map_async(MapMode::Read, |_| ());
     #[expect(clippy::expect_used)] // should never happen
     device
         .poll(PollType::Wait {
@@ -155,7 +364,11 @@ pub(crate) fn read_buffer(
             timeout: None,
         })
         .expect("internal error: cannot read buffer");
-    let view = slice.get_mapped_range();
+    unmap_read_buffer(read_buffer)
+}
+
+fn unmap_read_buffer(read_buffer: &Buffer) -> Vec<u8> {
+    let view = read_buffer.slice(..).get_mapped_range();
     let content = view.to_vec();
     drop(view);
     read_buffer.unmap();
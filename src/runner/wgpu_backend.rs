@@ -0,0 +1,182 @@
+use crate::Log;
+use crate::compiler::source_map::SourceMap;
+use crate::runner::backend::{Backend, BufferKind};
+use crate::runner::errors::ErrorScope;
+use crate::runner::profiler::{Profile, Profiler};
+use crate::runner::resources::{WgpuComputeShader, WgpuDrawShader};
+use crate::runner::target::{DEFAULT_TARGET_SIZE, Target};
+use crate::runner::utils;
+use wgpu::{Buffer, BufferUsages, Device, Queue};
+
+/// A [`Backend`] running programs on a graphic adapter with `wgpu`.
+#[derive(Debug)]
+pub struct WgpuBackend {
+    pub(crate) device: Device,
+    pub(crate) queue: Queue,
+    target: Target,
+    profiler: Option<Profiler>,
+}
+
+impl WgpuBackend {
+    /// Creates a new backend from an existing device, rendering into an offscreen texture.
+    pub fn new(device: Device, queue: Queue) -> Self {
+        let target = Target::new_texture(&device, DEFAULT_TARGET_SIZE);
+        Self::with_target(device, queue, target)
+    }
+
+    pub(crate) fn with_target(device: Device, queue: Queue, target: Target) -> Self {
+        Self {
+            device,
+            queue,
+            target,
+            profiler: None,
+        }
+    }
+
+    /// Returns the device used to run programs.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Returns the queue used to submit commands.
+    pub fn queue(&self) -> &Queue {
+        &self.queue
+    }
+
+    /// Starts measuring the GPU duration of each compute shader run.
+    ///
+    /// Returns `false` if the device doesn't support timestamp queries, in which case profiling
+    /// stays disabled. The device must be created with [`Features::TIMESTAMP_QUERY`](wgpu::Features::TIMESTAMP_QUERY).
+    pub fn enable_profiling(&mut self) -> bool {
+        if self.profiler.is_none() {
+            self.profiler = Profiler::new(&self.device, &self.queue);
+        }
+        self.profiler.is_some()
+    }
+
+    /// Returns the GPU timings measured since profiling has been enabled.
+    ///
+    /// `None` is returned if profiling is disabled.
+    pub fn profile(&self) -> Option<&Profile> {
+        self.profiler.as_ref().map(Profiler::profile)
+    }
+}
+
+impl Backend for WgpuBackend {
+    type Buffer = Buffer;
+    type ComputeShader = WgpuComputeShader;
+    type DrawShader = WgpuDrawShader;
+
+    fn create_buffer(
+        &mut self,
+        label: &str,
+        kind: BufferKind,
+        size: u64,
+    ) -> Result<Self::Buffer, Vec<Log>> {
+        let usage = match kind {
+            BufferKind::Storage => {
+                BufferUsages::STORAGE | BufferUsages::COPY_SRC | BufferUsages::COPY_DST
+            }
+            BufferKind::ReadOnlyStorage => BufferUsages::STORAGE | BufferUsages::COPY_DST,
+            BufferKind::Uniform => BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        };
+        let scope = ErrorScope::push(&self.device);
+        let buffer =
+            utils::create_buffer(&self.device, &format!("gpex:buffer:{label}"), usage, size);
+        scope.pop("buffer creation")?;
+        Ok(buffer)
+    }
+
+    fn write_buffer(
+        &mut self,
+        buffer: &Self::Buffer,
+        offset: u64,
+        bytes: &[u8],
+    ) -> Result<(), Vec<Log>> {
+        let scope = ErrorScope::push(&self.device);
+        self.queue.write_buffer(buffer, offset, bytes);
+        scope.pop("buffer write")
+    }
+
+    fn read_buffer(&self, buffer: &Self::Buffer, offset: u64, size: u64) -> Vec<u8> {
+        utils::read_buffer(&self.device, &self.queue, buffer, offset, size)
+    }
+
+    fn read_buffers(&self, buffers: &[(&Self::Buffer, u64)]) -> Vec<u8> {
+        utils::read_buffers(&self.device, &self.queue, buffers)
+    }
+
+    fn create_compute_shader(
+        &mut self,
+        label: &str,
+        code: &str,
+        source_map: &SourceMap,
+        buffers: &[&Self::Buffer],
+    ) -> Result<Self::ComputeShader, Vec<Log>> {
+        WgpuComputeShader::new(&self.device, label, code, source_map, buffers)
+    }
+
+    fn dispatch(&mut self, shader: &Self::ComputeShader) -> Result<(), Vec<Log>> {
+        let scope = ErrorScope::push(&self.device);
+        let mut encoder = utils::create_encoder(&self.device);
+        let timestamp_writes = self.profiler.as_ref().map(Profiler::timestamp_writes);
+        shader.run(&mut utils::start_compute_pass(
+            &mut encoder,
+            timestamp_writes,
+        ));
+        if let Some(profiler) = &self.profiler {
+            profiler.resolve(&mut encoder);
+        }
+        self.queue.submit(Some(encoder.finish()));
+        scope.pop("shader dispatch")?;
+        if let Some(profiler) = &mut self.profiler {
+            profiler.record(&self.device, &self.queue, shader.label());
+        }
+        Ok(())
+    }
+
+    fn create_draw_shader(
+        &mut self,
+        label: &str,
+        code: &str,
+        source_map: &SourceMap,
+        buffers: &[&Self::Buffer],
+    ) -> Result<Self::DrawShader, Vec<Log>> {
+        WgpuDrawShader::new(
+            &self.device,
+            label,
+            code,
+            source_map,
+            buffers,
+            self.target.format(),
+        )
+    }
+
+    fn render(&mut self, shaders: &[Self::DrawShader]) {
+        let Some(frame) = self.target.frame(&self.device) else {
+            return; // no-coverage (difficult to test)
+        };
+        let mut encoder = utils::create_encoder(&self.device);
+        let mut pass = utils::start_render_pass(&mut encoder, &frame.view);
+        for shader in shaders {
+            shader.draw(&mut pass);
+        }
+        drop(pass);
+        self.queue.submit(Some(encoder.finish()));
+        frame.present();
+    }
+
+    fn target_size(&self) -> (u32, u32) {
+        self.target.size()
+    }
+
+    fn resize_target(&mut self, width: u32, height: u32) {
+        self.target.resize(&self.device, (width, height));
+    }
+
+    fn read_target(&self) -> Option<Vec<u8>> {
+        self.target
+            .texture()
+            .map(|texture| utils::read_texture(&self.device, &self.queue, texture))
+    }
+}
@@ -0,0 +1,17 @@
+use crate::utils::logs::Log;
+use png::{BitDepth, ColorType, Encoder};
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+pub(crate) fn save_png(path: &Path, width: u32, height: u32, rgba: &[u8]) -> Result<(), Vec<Log>> {
+    let to_log = |error| vec![Log::from_io_error(error, path, "cannot write")];
+    let file = File::create(path).map_err(to_log)?;
+    let mut encoder = Encoder::new(BufWriter::new(file), width, height);
+    encoder.set_color(ColorType::Rgba);
+    encoder.set_depth(BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(rgba))
+        .map_err(|error| to_log(io::Error::other(error)))
+}
@@ -138,12 +138,24 @@ impl<Item: ItemNodeRef> NodeIndex<Item, false> {
         imports: &ImportIndex,
         is_visibility_ignored: bool,
     ) -> Option<Item> {
+        self.search_all(key, location, imports, is_visibility_ignored)
+            .next()
+    }
+
+    // Items are returned by decreasing priority
+    pub(crate) fn search_all(
+        &self,
+        key: &str,
+        location: impl NodeRef,
+        imports: &ImportIndex,
+        is_visibility_ignored: bool,
+    ) -> impl Iterator<Item = Item> {
         imports.imports[location.file_index()]
             .iter()
             .filter_map(|import| self.items[import.file_index].get(key))
             .flatten()
             .rev()
-            .find(|&&item| Self::is_item_visible(item, location, is_visibility_ignored))
+            .filter(move |&&item| Self::is_item_visible(item, location, is_visibility_ignored))
             .copied()
     }
 
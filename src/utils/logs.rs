@@ -87,22 +87,28 @@ pub struct LogLocation {
 
 impl Display for LogLocation {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut line = 1;
-        let mut column = 1;
-        for (offset, char) in self.code.char_indices() {
-            if offset == self.span.start {
-                break;
-            } else if char == '\n' {
-                line += 1;
-                column = 1;
-            } else {
-                column += 1;
-            }
-        }
+        let (line, column) = line_column(&self.code, self.span.start);
         write!(f, "{}:{line}:{column}", self.path.display())
     }
 }
 
+// 1-based line and column of a byte offset in a code
+pub(crate) fn line_column(code: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    for (char_offset, char) in code.char_indices() {
+        if char_offset == offset {
+            break;
+        } else if char == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
 /// The level of a compilation log.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LogLevel {
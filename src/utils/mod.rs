@@ -1,5 +1,7 @@
+pub(crate) mod image;
 pub(crate) mod indexing;
 pub(crate) mod logs;
 pub(crate) mod parsing;
 pub(crate) mod reading;
+pub(crate) mod shader;
 pub(crate) mod validation;
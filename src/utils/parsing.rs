@@ -1,5 +1,6 @@
 use crate::utils::logs::{Log, LogLevel, LogLocation};
 use crate::utils::reading::ReadFile;
+use itertools::Itertools;
 use std::ops::Range;
 use std::path::Path;
 
@@ -156,6 +157,7 @@ impl ParseError<'_> {
                 .filter(|error| error.offset == max_offset)
                 .flat_map(|error| error.expected_tokens.iter())
                 .copied()
+                .unique()
                 .collect(),
         }
     }
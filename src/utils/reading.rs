@@ -34,6 +34,24 @@ pub(crate) fn read(
     }
 }
 
+// FNV-1a hash, stable across platforms and Rust versions
+pub(crate) fn hash(files: &[ReadFile]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    files
+        .iter()
+        .flat_map(|file| {
+            let path = file.dot_path.as_bytes();
+            path.iter()
+                .chain([&0])
+                .chain(file.content.as_bytes())
+                .chain([&0])
+        })
+        .fold(OFFSET_BASIS, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
+}
+
 fn read_entry(
     entry: DirEntry,
     root_path: &Path,
@@ -0,0 +1,55 @@
+use naga::Module;
+use naga::valid::{Capabilities, ModuleInfo, ValidationFlags, Validator};
+use std::error::Error;
+use std::ops::Range;
+
+const SNIPPET_CONTEXT_LEN: usize = 40;
+
+#[derive(Debug)]
+pub(crate) struct ShaderError {
+    message: String,
+    span: Option<Range<usize>>,
+}
+
+impl ShaderError {
+    pub(crate) fn describe(&self, code: &str) -> String {
+        if let Some(span) = &self.span {
+            let start = code.floor_char_boundary(span.start.saturating_sub(SNIPPET_CONTEXT_LEN));
+            let end = code.ceil_char_boundary(span.end.saturating_add(SNIPPET_CONTEXT_LEN));
+            // the snippet is extended to the closest whitespaces to avoid cutting tokens
+            let start = code[..start].rfind(char::is_whitespace).unwrap_or(0);
+            let end = code[end..]
+                .find(char::is_whitespace)
+                .map_or(code.len(), |offset| end + offset);
+            format!("{} in `{}`", self.message, code[start..end].trim())
+        } else {
+            self.message.clone()
+        }
+    }
+}
+
+pub(crate) fn validate(code: &str) -> Result<(), ShaderError> {
+    parse(code).map(|_| ())
+}
+
+pub(crate) fn parse(code: &str) -> Result<(Module, ModuleInfo), ShaderError> {
+    let module = naga::front::wgsl::parse_str(code).map_err(|error| ShaderError {
+        message: error.message().to_string(),
+        span: error.labels().find_map(|(span, _)| span.to_range()),
+    })?;
+    let info = Validator::new(ValidationFlags::all(), Capabilities::all())
+        .validate(&module)
+        .map_err(|error| {
+            let span = error.spans().find_map(|(span, _)| span.to_range());
+            let error = error.into_inner();
+            // the innermost error is the most precise one
+            let mut message = error.to_string();
+            let mut source = error.source();
+            while let Some(inner) = source {
+                message = inner.to_string();
+                source = inner.source();
+            }
+            ShaderError { message, span }
+        })?;
+    Ok((module, info))
+}
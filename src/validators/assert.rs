@@ -0,0 +1,28 @@
+use crate::compiler::types::Type;
+use crate::utils::parsing::Span;
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::{Log, LogInner, LogLevel};
+
+pub(crate) fn check_condition_type(
+    condition_type: Option<Type>,
+    condition_span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    if let Some(condition_type) = condition_type
+        && condition_type != Type::I32
+    {
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!("`i32` value expected, found `{condition_type}` value"),
+            location: Some(context.location(condition_span)),
+            inner: vec![LogInner {
+                level: LogLevel::Info,
+                message: "assertion fails when its condition is zero".into(),
+                location: None,
+            }],
+        });
+        Err(ValidateError)
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::compiler::types::Type;
+use crate::language::items::ItemRef;
+use crate::language::items::fn_::{FunctionDefinition, FunctionParam};
+use crate::utils::parsing::{Span, SpanProperties};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::{Log, LogInner, LogLevel};
+
+pub(crate) fn check_function<'item>(
+    source: ItemRef<'item>,
+    span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<&'item FunctionDefinition, ValidateError> {
+    if let ItemRef::Function(function) = source {
+        Ok(function)
+    } else {
+        let slice = context.slice(span);
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!("`{slice}` value called as a function"),
+            location: Some(context.location(span)),
+            inner: vec![LogInner {
+                level: LogLevel::Info,
+                message: "value defined here".into(),
+                location: Some(context.location(source.name_span())),
+            }],
+        });
+        Err(ValidateError)
+    }
+}
+
+pub(crate) fn check_arg_count(
+    function: &FunctionDefinition,
+    arg_count: usize,
+    span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    let param_count = function.params.len();
+    if arg_count == param_count {
+        Ok(())
+    } else {
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!(
+                "`{}` function called with {arg_count} argument(s)",
+                function.name
+            ),
+            location: Some(context.location(span)),
+            inner: vec![LogInner {
+                level: LogLevel::Info,
+                message: format!("function defined with {param_count} parameter(s)"),
+                location: Some(context.location(function.name_span)),
+            }],
+        });
+        Err(ValidateError)
+    }
+}
+
+pub(crate) fn check_arg_type(
+    arg_type: Option<Type>,
+    arg_span: Span,
+    param: &FunctionParam,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    if let (Some(arg_type), Some(param_type)) = (arg_type, param.type_.type_())
+        && arg_type != param_type
+    {
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!("`{param_type}` value expected, found `{arg_type}` value"),
+            location: Some(context.location(arg_span)),
+            inner: vec![LogInner {
+                level: LogLevel::Info,
+                message: "parameter defined here".into(),
+                location: Some(context.location(param.name_span)),
+            }],
+        });
+        Err(ValidateError)
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::utils::parsing::Span;
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::{Log, LogInner, LogLevel};
+
+pub(crate) fn check_arg_count(
+    placeholder_count: usize,
+    arg_count: usize,
+    keyword_span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    if placeholder_count == arg_count {
+        Ok(())
+    } else {
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!("`debug_print` called with {arg_count} argument(s)"),
+            location: Some(context.location(keyword_span)),
+            inner: vec![LogInner {
+                level: LogLevel::Info,
+                message: format!(
+                    "format string contains {placeholder_count} `{{}}` placeholder(s)"
+                ),
+                location: None,
+            }],
+        });
+        Err(ValidateError)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::language::items::draw::RECTANGLE_PARAMS;
+use crate::utils::parsing::{Span, SpanProperties};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::{Log, LogInner, LogLevel};
+use itertools::Itertools;
+
+pub(crate) fn check_arg_count(
+    arg_count: usize,
+    shape_span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    if arg_count == RECTANGLE_PARAMS.len() {
+        Ok(())
+    } else {
+        let shape = context.slice(shape_span);
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!("`{shape}` drawn with {arg_count} argument(s)"),
+            location: Some(context.location(shape_span)),
+            inner: vec![LogInner {
+                level: LogLevel::Info,
+                message: format!(
+                    "expected arguments are `{}`",
+                    RECTANGLE_PARAMS.iter().join("`, `")
+                ),
+                location: None,
+            }],
+        });
+        Err(ValidateError)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::compiler::types::Type;
+use crate::utils::parsing::Span;
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::{Log, LogInner, LogLevel};
+
+pub(crate) fn check_constant(
+    is_constant: bool,
+    span: Span,
+    constant_mark_span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    if is_constant {
+        Ok(())
+    } else {
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: "expression not constant".into(),
+            location: Some(context.location(span)),
+            inner: vec![LogInner {
+                level: LogLevel::Info,
+                message: "expression must be constant".into(),
+                location: Some(context.location(constant_mark_span)),
+            }],
+        });
+        Err(ValidateError)
+    }
+}
+
+pub(crate) fn check_type(
+    expected_type: Type,
+    actual_type: Option<Type>,
+    span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    if let Some(actual_type) = actual_type
+        && actual_type != expected_type
+    {
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!("`{expected_type}` value expected, found `{actual_type}` value"),
+            location: Some(context.location(span)),
+            inner: vec![],
+        });
+        Err(ValidateError)
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::language::items::fn_::FunctionDefinition;
+use crate::utils::shader;
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::{Log, LogInner, LogLevel};
+
+pub(crate) fn check_wgsl(
+    function: &FunctionDefinition,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    let mut code = String::new();
+    function.transpile_wrapper(&mut code, "wrapper");
+    if let Err(error) = shader::validate(&code) {
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!(
+                "`{}` extern function doesn't map to a valid WGSL function",
+                function.name
+            ),
+            location: Some(context.location(function.name_span)),
+            inner: vec![LogInner {
+                level: LogLevel::Info,
+                message: error.describe(&code),
+                location: None,
+            }],
+        });
+        Err(ValidateError)
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::compiler::externs::ExternVariable;
+use crate::utils::parsing::{Span, SpanProperties};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::{Log, LogInner, LogLevel};
+
+pub(crate) fn check_found(
+    variable: Option<ExternVariable>,
+    name_span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<ExternVariable, ValidateError> {
+    if let Some(variable) = variable {
+        Ok(variable)
+    } else {
+        let name = context.slice(name_span);
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!("`{name}` extern variable not provided by the runner"),
+            location: Some(context.location(name_span)),
+            inner: vec![],
+        });
+        Err(ValidateError)
+    }
+}
+
+pub(crate) fn check_read_only(
+    value_span: Option<Span>,
+    name_span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    if let Some(value_span) = value_span {
+        let name = context.slice(name_span);
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!("`{name}` extern variable written"),
+            location: Some(context.location(value_span)),
+            inner: vec![LogInner {
+                level: LogLevel::Info,
+                message: "extern variables are read-only, their value is provided by the runner"
+                    .into(),
+                location: Some(context.location(name_span)),
+            }],
+        });
+        Err(ValidateError)
+    } else {
+        Ok(())
+    }
+}
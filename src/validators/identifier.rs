@@ -4,6 +4,7 @@ use crate::utils::indexing::{ItemNodeRef, NodeRef};
 use crate::utils::parsing::{Span, SpanProperties};
 use crate::utils::validation::{ValidateContext, ValidateError};
 use crate::{Log, LogInner, LogLevel};
+use std::iter;
 
 pub(crate) fn check_found(
     node: impl NodeRef,
@@ -45,27 +46,67 @@ pub(crate) fn check_found(
     }
 }
 
-pub(crate) fn check_constant(
+pub(crate) fn check_ambiguity(
     node: impl NodeRef,
     span: Span,
-    constant_mark_span: Span,
     context: &mut ValidateContext<'_>,
     indexes: &Indexes<'_>,
+) {
+    if let Some(shadowed_sources) = indexes.shadowed_sources.get(&node.id()) {
+        let slice = context.slice(span);
+        let source = indexes.sources[&node.id()];
+        context.logs.push(Log {
+            level: LogLevel::Warning,
+            message: format!("`{slice}` {} is ambiguous", source.kind_name()),
+            location: Some(context.location(span)),
+            inner: iter::once(LogInner {
+                level: LogLevel::Info,
+                message: format!(
+                    "{} selected from `{}`",
+                    source.kind_name(),
+                    context.dot_path(source.file_index())
+                ),
+                location: Some(context.location(source.name_span())),
+            })
+            .chain(shadowed_sources.iter().map(|shadowed| LogInner {
+                level: LogLevel::Info,
+                message: format!(
+                    "{} also visible from `{}`",
+                    shadowed.kind_name(),
+                    context.dot_path(shadowed.file_index())
+                ),
+                location: Some(context.location(shadowed.name_span())),
+            }))
+            .chain(iter::once(LogInner {
+                level: LogLevel::Info,
+                message: "rename the conflicting items or remove the unneeded imports".into(),
+                location: None,
+            }))
+            .collect(),
+        });
+    }
+}
+
+pub(crate) fn check_value(
+    source: ItemRef<'_>,
+    span: Span,
+    context: &mut ValidateContext<'_>,
 ) -> Result<(), ValidateError> {
-    if matches!(indexes.sources[&node.id()], ItemRef::Constant(_)) {
-        Ok(())
-    } else {
+    if let ItemRef::Function(function) = source {
+        let slice = context.slice(span);
         context.logs.push(Log {
             level: LogLevel::Error,
-            message: "expression not constant".into(),
+            message: format!("`{slice}` function used as a value"),
             location: Some(context.location(span)),
             inner: vec![LogInner {
                 level: LogLevel::Info,
-                message: "expression must be constant".into(),
-                location: Some(context.location(constant_mark_span)),
+                message: "function defined here".into(),
+                location: Some(context.location(function.name_span)),
             }],
         });
         Err(ValidateError)
+    } else {
+        Ok(())
     }
 }
 
@@ -1,4 +1,12 @@
+pub(crate) mod assert;
+pub(crate) mod call;
+pub(crate) mod debug_print;
+pub(crate) mod draw;
+pub(crate) mod expression;
+pub(crate) mod extern_fn;
+pub(crate) mod extern_var;
 pub(crate) mod identifier;
 pub(crate) mod import;
 pub(crate) mod item;
 pub(crate) mod literal;
+pub(crate) mod type_;
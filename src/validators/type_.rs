@@ -0,0 +1,23 @@
+use crate::compiler::types::Type;
+use crate::utils::parsing::{Span, SpanProperties};
+use crate::utils::validation::{ValidateContext, ValidateError};
+use crate::{Log, LogLevel};
+
+pub(crate) fn check_found(
+    type_: Option<Type>,
+    span: Span,
+    context: &mut ValidateContext<'_>,
+) -> Result<(), ValidateError> {
+    if type_.is_some() {
+        Ok(())
+    } else {
+        let slice = context.slice(span);
+        context.logs.push(Log {
+            level: LogLevel::Error,
+            message: format!("`{slice}` type not found"),
+            location: Some(context.location(span)),
+            inner: vec![],
+        });
+        Err(ValidateError)
+    }
+}
@@ -0,0 +1,227 @@
+use gpex::{Backend, BufferKind, CompileOptions, GpuValue, Log, LogLevel, Runner, SourceMap};
+use std::cell::Cell;
+use std::fs;
+use std::path::Path;
+
+// backend recording requested operations instead of running them
+#[derive(Debug, Default)]
+struct RecordingBackend {
+    buffers: Vec<(String, Vec<u8>)>,
+    operations: Vec<String>,
+    read_count: Cell<usize>,
+    target_size: (u32, u32),
+    is_write_failing: Cell<bool>,
+}
+
+impl RecordingBackend {
+    fn buffer(&self, label: &str) -> Option<&[u8]> {
+        self.buffers
+            .iter()
+            .find(|(buffer_label, _)| buffer_label == label)
+            .map(|(_, bytes)| bytes.as_slice())
+    }
+
+    fn count(&self, operation: &str) -> usize {
+        self.operations
+            .iter()
+            .filter(|recorded| *recorded == operation)
+            .count()
+    }
+}
+
+impl Backend for RecordingBackend {
+    type Buffer = usize;
+    type ComputeShader = String;
+    type DrawShader = String;
+
+    fn create_buffer(
+        &mut self,
+        label: &str,
+        _kind: BufferKind,
+        size: u64,
+    ) -> Result<Self::Buffer, Vec<Log>> {
+        self.operations.push(format!("create {label} buffer"));
+        self.buffers.push((label.into(), vec![0; to_index(size)]));
+        Ok(self.buffers.len() - 1)
+    }
+
+    fn write_buffer(
+        &mut self,
+        &buffer: &Self::Buffer,
+        offset: u64,
+        bytes: &[u8],
+    ) -> Result<(), Vec<Log>> {
+        if self.is_write_failing.get() {
+            return Err(vec![Log {
+                level: LogLevel::Error,
+                message: "write failed".into(),
+                location: None,
+                inner: vec![],
+            }]);
+        }
+        let (label, content) = &mut self.buffers[buffer];
+        self.operations.push(format!("write {label} buffer"));
+        let offset = to_index(offset);
+        content[offset..offset + bytes.len()].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    fn read_buffer(&self, &buffer: &Self::Buffer, offset: u64, size: u64) -> Vec<u8> {
+        self.read_count.set(self.read_count.get() + 1);
+        self.buffers[buffer].1[to_index(offset)..to_index(offset + size)].to_vec()
+    }
+
+    fn read_buffers(&self, buffers: &[(&Self::Buffer, u64)]) -> Vec<u8> {
+        self.read_count.set(self.read_count.get() + 1);
+        buffers
+            .iter()
+            .flat_map(|&(&buffer, size)| &self.buffers[buffer].1[..to_index(size)])
+            .copied()
+            .collect()
+    }
+
+    fn create_compute_shader(
+        &mut self,
+        label: &str,
+        _code: &str,
+        _source_map: &SourceMap,
+        buffers: &[&Self::Buffer],
+    ) -> Result<Self::ComputeShader, Vec<Log>> {
+        assert!(buffers.len() >= 3);
+        self.operations.push(format!("create {label}"));
+        Ok(label.into())
+    }
+
+    fn dispatch(&mut self, shader: &Self::ComputeShader) -> Result<(), Vec<Log>> {
+        self.operations.push(format!("dispatch {shader}"));
+        Ok(())
+    }
+
+    fn create_draw_shader(
+        &mut self,
+        label: &str,
+        _code: &str,
+        _source_map: &SourceMap,
+        _buffers: &[&Self::Buffer],
+    ) -> Result<Self::DrawShader, Vec<Log>> {
+        self.operations.push(format!("create {label}"));
+        Ok(label.into())
+    }
+
+    fn render(&mut self, shaders: &[Self::DrawShader]) {
+        self.operations
+            .extend(shaders.iter().map(|shader| format!("render {shader}")));
+    }
+
+    fn target_size(&self) -> (u32, u32) {
+        self.target_size
+    }
+
+    fn resize_target(&mut self, width: u32, height: u32) {
+        self.target_size = (width.max(1), height.max(1));
+    }
+
+    fn read_target(&self) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+#[expect(clippy::cast_possible_truncation)] // test buffers are small
+fn to_index(value: u64) -> usize {
+    value as usize
+}
+
+#[test]
+fn dispatch_init_shader_once() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut runner = Runner::with_backend(program, RecordingBackend::default())?;
+    assert_eq!(runner.backend().count("create init shader"), 1);
+    for _ in 0..3 {
+        runner.run_step()?;
+    }
+    assert_eq!(runner.frame_index(), 3);
+    assert_eq!(runner.backend().count("dispatch init shader"), 1);
+    assert_eq!(runner.backend().count("write time buffer"), 3);
+    assert_eq!(runner.backend().count("write input buffer"), 3);
+    Ok(())
+}
+
+#[test]
+fn run_program_without_variables_with_backend() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/draw_only"), false)?.0;
+    let mut runner = Runner::with_backend(program, RecordingBackend::default())?;
+    runner.run_step()?;
+    runner.render();
+    let backend = runner.backend();
+    assert_eq!(backend.buffer("main"), None);
+    assert_eq!(backend.count("create init shader"), 0);
+    assert_eq!(backend.count("dispatch init shader"), 0);
+    assert_eq!(backend.count("render draw shader #0"), 1);
+    assert!(runner.snapshot().is_empty());
+    assert_eq!(runner.capture_frame(), None);
+    Ok(())
+}
+
+#[test]
+fn write_and_read_variable_with_backend() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut runner = Runner::with_backend(program, RecordingBackend::default())?;
+    runner.write_variable("root:_root_value", GpuValue::I32(42))?;
+    assert_eq!(
+        runner.read_variable("root:_root_value"),
+        Some(GpuValue::I32(42))
+    );
+    assert_eq!(runner.snapshot()["root:_root_value"], GpuValue::I32(42));
+    assert!(
+        runner
+            .write_variable("root:missing", GpuValue::I32(0))
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn resize_target_with_backend() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/draw_only"), false)?.0;
+    let mut runner = Runner::with_backend(program, RecordingBackend::default())?;
+    runner.resize(0, 50);
+    assert_eq!(runner.target_size(), (1, 50));
+    let size = [1_u32.to_ne_bytes(), 50_u32.to_ne_bytes()].concat();
+    assert_eq!(
+        runner.backend().buffer("target_size"),
+        Some(size.as_slice())
+    );
+    Ok(())
+}
+
+#[test]
+fn read_split_buffer_at_once_with_backend() -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.limits.max_storage_buffer_binding_size = 8;
+    let program = gpex::compile_with_options(Path::new("tests/lib/split"), options)?.0;
+    assert!(program.buffer.parts.len() > 1);
+    let runner = Runner::with_backend(program, RecordingBackend::default())?;
+    let values = runner.snapshot();
+    assert_eq!(values.len(), 5);
+    assert_eq!(runner.backend().read_count.get(), 1);
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn load_state_with_failing_backend() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut runner = Runner::with_backend(program.clone(), RecordingBackend::default())?;
+    runner.run_step()?;
+    let path = Path::new("tests/lib/backend_state.json");
+    runner.save_state(path)?;
+    let mut runner = Runner::with_backend(program, RecordingBackend::default())?;
+    runner.backend().is_write_failing.set(true);
+    let result = runner.load_state(path);
+    if path.is_file() {
+        _ = fs::remove_file(path);
+    }
+    let errors = result.expect_err("loading should generate errors");
+    assert_eq!(errors[0].message, "write failed");
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use gpex::{Log, LogLevel};
+use gpex::{CompileOptions, Log, LogLevel, TargetLimits, Type};
 use std::path::Path;
 
 #[test]
@@ -10,6 +10,115 @@ fn compile_valid_project() -> Result<(), Vec<Log>> {
     assert_eq!(fields.len(), 2);
     assert!(fields.contains_key("root:_root_value"));
     assert!(fields.contains_key("inner.inner2.inner:_inner_value"));
+    assert!(program.draw_shaders.is_empty());
+    Ok(())
+}
+
+#[test]
+fn compile_project_with_draws() -> Result<(), Vec<Log>> {
+    let (program, logs) = gpex::compile(Path::new("tests/lib/draw"), false)?;
+    assert!(logs.is_empty());
+    assert_eq!(program.draw_shaders.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn compile_project_without_variables() -> Result<(), Vec<Log>> {
+    let (program, logs) = gpex::compile(Path::new("tests/lib/draw_only"), false)?;
+    assert!(logs.is_empty());
+    assert!(program.init_shader.is_empty());
+    assert_eq!(program.buffer.size, 0);
+    Ok(())
+}
+
+#[test]
+fn compile_project_with_typed_variables() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/time"), false)?;
+    let fields = &program.buffer.fields;
+    assert_eq!(fields["main:first_frame_index"].type_, Type::I32);
+    assert_eq!(fields["main:first_delta"].type_, Type::F32);
+    assert_eq!(fields["main:first_elapsed"].size, 4);
+    Ok(())
+}
+
+#[test]
+fn compile_project_with_source_maps() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/draw"), false)?;
+    assert_eq!(program.draw_source_maps.len(), 2);
+    let shader = &program.init_shader;
+    let init_column = shader.find(" = i32(20)").map_or(0, |offset| offset);
+    let mapping = program.init_source_map.find(shader, 1, init_column);
+    assert_eq!(
+        mapping.map(|mapping| mapping.item.as_str()),
+        Some("main:size")
+    );
+    assert_eq!(
+        mapping.map(|mapping| (mapping.line, mapping.column)),
+        Some((1, 5))
+    );
+    assert!(mapping.is_some_and(|mapping| mapping.path == Path::new("tests/lib/draw/main.gpex")));
+    let shader = &program.draw_shaders[1];
+    let draw_column = shader.find("f32(i32(40))").map_or(0, |offset| offset + 1);
+    let mapping = program.draw_source_maps[1].find(shader, 1, draw_column);
+    assert_eq!(
+        mapping.map(|mapping| mapping.item.as_str()),
+        Some("main:draw")
+    );
+    assert_eq!(
+        mapping.map(|mapping| (mapping.line, mapping.column)),
+        Some((4, 6))
+    );
+    assert!(program.init_source_map.find(shader, 1, 1).is_none());
+    assert!(program.init_source_map.find(shader, 2, 1).is_none());
+    assert!(program.init_source_map.find(shader, 1, 0).is_none());
+    Ok(())
+}
+
+#[test]
+fn compile_pretty_project() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/draw"), false)?;
+    let program = program.into_pretty();
+    let shader = &program.init_shader;
+    let lines: Vec<_> = shader.lines().collect();
+    assert!(lines.len() > 10);
+    assert_eq!(lines[0], "struct Buffer {");
+    assert!(lines[1].starts_with("    v") && lines[1].ends_with(": i32, // main:size"));
+    assert!(lines.contains(&"@group(0) @binding(1) var<storage, read> i: Input;"));
+    let init_line = lines
+        .iter()
+        .position(|line| line.ends_with(" = i32(20); // main:size"))
+        .map_or(0, |index| index + 1);
+    let mapping = program.init_source_map.find(shader, init_line, 5);
+    assert_eq!(
+        mapping.map(|mapping| mapping.item.as_str()),
+        Some("main:size")
+    );
+    let pretty_program = program.clone().into_pretty();
+    assert_eq!(pretty_program.init_shader, program.init_shader);
+    assert_eq!(pretty_program.draw_shaders, program.draw_shaders);
+    assert_eq!(
+        pretty_program.init_source_map.mappings,
+        program.init_source_map.mappings
+    );
+    Ok(())
+}
+
+#[test]
+fn compile_pretty_project_with_shift_operators() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/pretty"), false)?;
+    let program = program.into_pretty();
+    let lines: Vec<_> = program.init_shader.lines().collect();
+    assert!(lines.iter().any(|line| {
+        line.contains(" = f")
+            && line.ends_with("(i32((i.keys[0] >> 0u) & 1u), i32(2)); // main:_max_key")
+    }));
+    assert!(lines.contains(&"    return max(first, second);"));
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.contains(" = f") && line.ends_with("(i32(1)); // main:_noise"))
+    );
+    assert!(lines.contains(&"    return f32(((word >> 22u) ^ word) >> 8u) / 16777216.0;"));
     Ok(())
 }
 
@@ -30,6 +139,45 @@ fn compile_with_warning_as_error() {
     assert_eq!(errors[0].level, LogLevel::Warning);
 }
 
+#[test]
+fn compile_with_shadowed_prelude_item() -> Result<(), Vec<Log>> {
+    let (_, logs) = gpex::compile(Path::new("tests/lib/shadowing"), false)?;
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].level, LogLevel::Warning);
+    assert_eq!(logs[0].message, "`ZERO` constant is ambiguous");
+    assert_eq!(logs[0].inner.len(), 3);
+    assert_eq!(
+        logs[0].inner[1].message,
+        "constant also visible from `prelude`"
+    );
+    Ok(())
+}
+
+#[test]
+fn compile_with_shadowed_function() -> Result<(), Vec<Log>> {
+    let (_, logs) = gpex::compile(Path::new("tests/lib/shadowing_function"), false)?;
+    let log = logs
+        .iter()
+        .find(|log| log.message.ends_with("is ambiguous"));
+    assert_eq!(
+        log.map(|log| log.message.as_str()),
+        Some("`min` function is ambiguous")
+    );
+    let inner: Vec<_> = log
+        .into_iter()
+        .flat_map(|log| &log.inner)
+        .map(|inner| inner.message.as_str())
+        .collect();
+    assert_eq!(
+        inner[..2],
+        [
+            "function selected from `prelude.math`",
+            "variable also visible from `values`"
+        ]
+    );
+    Ok(())
+}
+
 #[test]
 #[expect(clippy::expect_used)]
 fn compile_with_error() {
@@ -54,3 +202,127 @@ fn compile_missing_folder() {
             .starts_with("error: cannot read \"tests/lib/missing\": ")
     );
 }
+
+#[test]
+fn compile_with_split_buffer() -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.limits.max_storage_buffer_binding_size = 8;
+    let (program, _) = gpex::compile_with_options(Path::new("tests/lib/split"), options)?;
+    let buffer = &program.buffer;
+    assert_eq!(buffer.size, 20);
+    assert_eq!(buffer.parts.len(), 3);
+    assert_eq!((buffer.parts[2].offset, buffer.parts[2].size), (16, 4));
+    let field = &buffer.fields["main:fifth"];
+    assert_eq!(field.offset, 16);
+    assert_eq!(buffer.part_index(field), Some(2));
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn compile_with_too_small_limits() {
+    let mut options = CompileOptions::default();
+    options.limits.max_storage_buffer_binding_size = 8;
+    options.limits.max_storage_buffers_per_shader_stage = 3;
+    let result = gpex::compile_with_options(Path::new("tests/lib/split"), options);
+    let errors = result.expect_err("compilation should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "error: program variables exceed the storage buffer limits of the target \
+        (at tests/lib/split/main.gpex:7:5)\n  \
+        --> info: variable doesn't fit in the storage buffers available for variables\n  \
+        --> info: target supports storage buffers of at most `8` bytes, and at most `3` \
+        storage buffers per shader including `1` for extern variables\n"
+    );
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn compile_with_too_small_buffer_size() {
+    let mut options = CompileOptions::default();
+    options.limits.max_storage_buffer_binding_size = 2;
+    let result = gpex::compile_with_options(Path::new("tests/lib/split"), options);
+    let errors = result.expect_err("compilation should generate errors");
+    assert_eq!(errors.len(), 5);
+    assert_eq!(
+        errors[0].inner[0].message,
+        "variable of `4` bytes is larger than the maximum storage buffer size"
+    );
+}
+
+#[test]
+fn compile_project_with_asserts() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/assert"), false)?;
+    assert_eq!(program.asserts.len(), 2);
+    assert_eq!(program.asserts[1].message, "value is positive");
+    assert_eq!(
+        program.asserts[1].path,
+        Path::new("tests/lib/assert/main.gpex")
+    );
+    assert_eq!((program.asserts[1].line, program.asserts[1].column), (6, 1));
+    assert!(program.init_shader.contains("assert_failures"));
+    Ok(())
+}
+
+#[test]
+fn compile_project_with_only_asserts() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/assert_only"), false)?;
+    assert_eq!(program.asserts.len(), 1);
+    assert_eq!(program.buffer.size, 0);
+    assert!(!program.init_shader.is_empty());
+    Ok(())
+}
+
+#[test]
+fn compile_project_with_debug_prints() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/debug_print"), false)?;
+    assert_eq!(program.debug_prints.len(), 3);
+    let print = &program.debug_prints[1];
+    assert_eq!(print.format, "delta = {}, frame = {}");
+    assert_eq!(print.arg_types, [Type::F32, Type::I32]);
+    assert_eq!((print.line, print.column), (5, 1));
+    assert!(program.asserts.is_empty());
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn compile_with_asserts_and_too_small_limits() {
+    let mut options = CompileOptions::default();
+    options.limits.max_storage_buffers_per_shader_stage = 2;
+    let result = gpex::compile_with_options(Path::new("tests/lib/assert"), options);
+    let errors = result.expect_err("compilation should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].inner[1].message,
+        format!(
+            "target supports storage buffers of at most `{}` bytes, and at most `2` storage \
+            buffers per shader including `1` for extern variables and `1` for assertions and debug prints",
+            options.limits.max_storage_buffer_binding_size
+        )
+    );
+}
+
+#[test]
+fn compile_with_downlevel_limits() -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.limits = TargetLimits::downlevel();
+    let (program, _) = gpex::compile_with_options(Path::new("tests/lib/split"), options)?;
+    assert_eq!(program.buffer.parts.len(), 1);
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn compile_with_zero_debug_print_capacity() {
+    let mut options = CompileOptions::default();
+    options.debug_print_capacity = 0;
+    let result = gpex::compile_with_options(Path::new("tests/lib/debug_print"), options);
+    let errors = result.expect_err("compilation should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "debug print capacity must be at least one word"
+    );
+}
@@ -0,0 +1,91 @@
+use gpex::{Log, LogLevel, ShaderFormat};
+use std::fs;
+use std::path::Path;
+
+const SPIRV_MAGIC_NUMBER: u32 = 0x0723_0203;
+
+#[test]
+fn emit_all_formats() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/draw"), false)?;
+    let folder = Path::new("tests/lib/out_emit_all");
+    let formats = [
+        ShaderFormat::Wgsl,
+        ShaderFormat::SpirV,
+        ShaderFormat::Msl,
+        ShaderFormat::Hlsl,
+        ShaderFormat::Glsl,
+    ];
+    let result = gpex::emit_shaders(&program, &formats, folder);
+    let wgsl = fs::read_to_string(folder.join("init.wgsl"));
+    let spirv = fs::read(folder.join("draw1.spv"));
+    let glsl = fs::read_to_string(folder.join("draw0.fs_main.glsl"));
+    if folder.is_dir() {
+        _ = fs::remove_dir_all(folder);
+    }
+    let paths = result?;
+    assert_eq!(paths.len(), 17);
+    assert!(paths.contains(&folder.join("init.main.glsl")));
+    assert!(paths.contains(&folder.join("draw0.vs_main.glsl")));
+    assert!(paths.contains(&folder.join("draw1.metal")));
+    assert!(paths.contains(&folder.join("draw1.hlsl")));
+    assert_eq!(wgsl.ok(), Some(program.init_shader));
+    assert!(
+        spirv
+            .ok()
+            .is_some_and(|spirv| spirv.starts_with(&SPIRV_MAGIC_NUMBER.to_le_bytes()))
+    );
+    assert!(glsl.is_ok_and(|glsl| glsl.starts_with("#version 450")));
+    Ok(())
+}
+
+#[test]
+fn emit_program_without_variables() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/draw_only"), false)?;
+    let folder = Path::new("tests/lib/out_emit_without_variables");
+    let result = gpex::emit_shaders(&program, &[ShaderFormat::Wgsl], folder);
+    if folder.is_dir() {
+        _ = fs::remove_dir_all(folder);
+    }
+    assert_eq!(result?, [folder.join("draw0.wgsl")]);
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn emit_in_invalid_folder() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/valid"), false)?;
+    let result = gpex::emit_shaders(
+        &program,
+        &[ShaderFormat::Wgsl],
+        Path::new("tests/lib/main.rs/out"),
+    );
+    let errors = result.expect_err("emission should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].level, LogLevel::Error);
+    assert!(
+        errors[0]
+            .to_string()
+            .starts_with("error: cannot write \"tests/lib/main.rs/out\": ")
+    );
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn emit_program_with_invalid_shader() -> Result<(), Vec<Log>> {
+    let (mut program, _) = gpex::compile(Path::new("tests/lib/valid"), false)?;
+    program.init_shader = "fn main() -> i32 {}".into();
+    let folder = Path::new("tests/lib/out_emit_invalid_shader");
+    let result = gpex::emit_shaders(&program, &[ShaderFormat::Wgsl], folder);
+    if folder.is_dir() {
+        _ = fs::remove_dir_all(folder);
+    }
+    let errors = result.expect_err("emission should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "internal error: invalid init shader in program"
+    );
+    assert_eq!(errors[0].inner.len(), 1);
+    Ok(())
+}
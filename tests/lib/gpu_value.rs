@@ -1,6 +1,17 @@
-use gpex::GpuValue;
+use gpex::{GpuValue, Type};
 
 #[test]
 fn display_i32() {
     assert_eq!(format!("{}", GpuValue::I32(123)), "123");
 }
+
+#[test]
+fn display_f32() {
+    assert_eq!(format!("{}", GpuValue::F32(1.5)), "1.5");
+}
+
+#[test]
+fn retrieve_type() {
+    assert_eq!(GpuValue::I32(1).type_(), Type::I32);
+    assert_eq!(GpuValue::F32(1.).type_(), Type::F32);
+}
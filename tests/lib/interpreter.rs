@@ -0,0 +1,243 @@
+use gpex::{CompileOptions, GpuValue, Interpreter, Log, LogLevel, ProgramRunner};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+#[test]
+fn run_program_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    interpreter.run_step()?;
+    assert_eq!(
+        interpreter.read_variable("inner.inner2.inner:_inner_value"),
+        Some(GpuValue::I32(1))
+    );
+    assert_eq!(
+        interpreter.read_variable("root:_root_value"),
+        Some(GpuValue::I32(2))
+    );
+    assert_eq!(interpreter.read_variable("module:invalid"), None);
+    Ok(())
+}
+
+#[test]
+fn run_program_without_variables_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/draw_only"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    interpreter.run_step()?;
+    assert!(interpreter.snapshot().is_empty());
+    Ok(())
+}
+
+#[test]
+fn run_program_with_input_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/input"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    let input = interpreter.input_mut();
+    input.set("key_space", 1);
+    input.set("mouse_x", 12);
+    input.set("mouse_y", -3);
+    input.set("mouse_right", 1);
+    input.set("gamepad_left_y", -1000);
+    interpreter.run_step()?;
+    let values = interpreter.read_variables(&[
+        "main:pressed_key",
+        "main:released_key",
+        "main:position_x",
+        "main:position_y",
+        "main:button",
+        "main:axis",
+        "main:missing",
+    ]);
+    assert_eq!(values.len(), 6);
+    assert_eq!(values["main:pressed_key"], GpuValue::I32(1));
+    assert_eq!(values["main:released_key"], GpuValue::I32(0));
+    assert_eq!(values["main:position_x"], GpuValue::I32(12));
+    assert_eq!(values["main:position_y"], GpuValue::I32(-3));
+    assert_eq!(values["main:button"], GpuValue::I32(1));
+    assert_eq!(values["main:axis"], GpuValue::I32(-1000));
+    Ok(())
+}
+
+#[test]
+fn run_program_with_fixed_timestep_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    interpreter.set_fixed_timestep(Some(Duration::from_secs(2)));
+    for _ in 0..3 {
+        interpreter.run_step()?;
+    }
+    assert_eq!(interpreter.frame_index(), 3);
+    assert_eq!(
+        interpreter.read_variable("main:first_frame_index"),
+        Some(GpuValue::I32(0))
+    );
+    assert_eq!(
+        interpreter.read_variable("main:first_delta"),
+        Some(GpuValue::F32(2.))
+    );
+    Ok(())
+}
+
+#[test]
+fn run_program_with_huge_fixed_timestep_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    interpreter.set_fixed_timestep(Some(Duration::MAX));
+    for _ in 0..3 {
+        interpreter.run_step()?;
+    }
+    assert_eq!(interpreter.frame_index(), 3);
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn write_variable_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    interpreter.run_step()?;
+    interpreter.write_variable("main:first_frame_index", GpuValue::I32(42))?;
+    interpreter.run_step()?;
+    assert_eq!(
+        interpreter.read_variable("main:first_frame_index"),
+        Some(GpuValue::I32(42))
+    );
+    let errors = interpreter
+        .write_variable("main:first_delta", GpuValue::I32(1))
+        .expect_err("writing should generate errors");
+    assert_eq!(
+        errors[0].message,
+        "`i32` value written in `main:first_delta` variable of type `f32`"
+    );
+    let errors = interpreter
+        .write_variable("main:missing", GpuValue::I32(1))
+        .expect_err("writing should generate errors");
+    assert_eq!(errors[0].message, "`main:missing` variable not found");
+    Ok(())
+}
+
+#[test]
+fn save_and_load_state_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut interpreter = Interpreter::new(program.clone())?;
+    interpreter.set_fixed_timestep(Some(Duration::from_secs(1)));
+    interpreter.run_step()?;
+    interpreter.write_variable("main:first_frame_index", GpuValue::I32(7))?;
+    let out_path = Path::new("tests/lib/out_cpu_state.json");
+    let result = interpreter.save_state(out_path);
+    let mut interpreter = Interpreter::new(program)?;
+    let warnings = interpreter.load_state(out_path);
+    if out_path.is_file() {
+        _ = fs::remove_file(out_path);
+    }
+    result?;
+    assert!(warnings?.is_empty());
+    interpreter.run_step()?;
+    assert_eq!(
+        interpreter.read_variable("main:first_frame_index"),
+        Some(GpuValue::I32(7))
+    );
+    assert_eq!(
+        interpreter.read_variable("main:first_delta"),
+        Some(GpuValue::F32(1.))
+    );
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn run_unsupported_function_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/unsupported"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    let errors = interpreter
+        .run_step()
+        .expect_err("running should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].level, LogLevel::Error);
+    assert_eq!(errors[0].message, "init shader cannot be interpreted");
+    assert_eq!(errors[0].inner.len(), 1);
+    assert!(
+        errors[0].inner[0]
+            .message
+            .starts_with("unsupported `Fma` function")
+    );
+    Ok(())
+}
+
+#[test]
+fn run_program_with_split_buffer_on_cpu() -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.limits.max_storage_buffer_binding_size = 8;
+    let program = gpex::compile_with_options(Path::new("tests/lib/split"), options)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    interpreter.run_step()?;
+    let values = interpreter.snapshot();
+    assert_eq!(values.len(), 5);
+    assert_eq!(values["main:third"], GpuValue::I32(3));
+    assert_eq!(values["main:fifth"], GpuValue::I32(5));
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn run_program_with_failed_assert_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/assert"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    let errors = interpreter
+        .run_step()
+        .expect_err("step should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "assertion failed: value is positive");
+    assert!(errors[0].inner.is_empty());
+    assert_eq!(
+        interpreter.read_variable("main:value"),
+        Some(GpuValue::I32(2))
+    );
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn run_program_with_only_asserts_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/assert_only"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    let errors = interpreter
+        .run_step()
+        .expect_err("step should generate errors");
+    assert_eq!(errors[0].message, "assertion failed: always fails");
+    Ok(())
+}
+
+#[test]
+fn run_program_with_debug_prints_on_cpu() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/debug_print"), false)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    interpreter.set_fixed_timestep(Some(Duration::from_millis(500)));
+    interpreter.run_step()?;
+    let messages: Vec<_> = interpreter
+        .take_debug_logs()
+        .into_iter()
+        .map(|log| log.message)
+        .collect();
+    assert_eq!(
+        messages,
+        ["value = 2", "delta = 0.5, frame = 0", "no argument"]
+    );
+    Ok(())
+}
+
+#[test]
+fn run_program_with_dropped_debug_prints_on_cpu() -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.debug_print_capacity = 10;
+    let path = Path::new("tests/lib/debug_print_overflow");
+    let program = gpex::compile_with_options(path, options)?.0;
+    let mut interpreter = Interpreter::new(program)?;
+    interpreter.run_step()?;
+    let logs = interpreter.take_debug_logs();
+    assert_eq!(logs.len(), 3);
+    assert_eq!(logs[1].message, "1 1 1");
+    assert_eq!(logs[2].level, LogLevel::Warning);
+    Ok(())
+}
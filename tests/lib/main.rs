@@ -1,6 +1,10 @@
 //! Tests for the `GPEx` library.
 
+mod backend;
 mod compiler;
+mod emission;
 mod gpu_value;
+mod interpreter;
 mod runner;
 mod serialization;
+mod testing;
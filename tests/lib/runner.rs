@@ -1,11 +1,14 @@
-use gpex::{GpuValue, Log, Runner};
+use gpex::{AdapterOptions, CompileOptions, GpuValue, Input, InputScript, Log, LogLevel, Runner};
+use std::fs;
+use std::io::BufReader;
 use std::path::Path;
+use std::time::Duration;
 
 #[tokio::test]
 async fn run_program() -> Result<(), Vec<Log>> {
     let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
     let mut runner = Runner::new(program).await?;
-    runner.run_step();
+    runner.run_step()?;
     assert_eq!(
         runner.read_variable("inner.inner2.inner:_inner_value"),
         Some(GpuValue::I32(1))
@@ -17,3 +20,749 @@ async fn run_program() -> Result<(), Vec<Log>> {
     assert_eq!(runner.read_variable("module:invalid"), None);
     Ok(())
 }
+
+#[tokio::test]
+async fn render_program() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/draw"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.run_step()?;
+    runner.render();
+    runner.resize(0, 50);
+    assert_eq!(runner.target_size(), (1, 50));
+    runner.render();
+    Ok(())
+}
+
+#[tokio::test]
+async fn capture_frame() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/draw"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.resize(320, 240);
+    runner.run_step()?;
+    let frame = runner.capture_frame();
+    assert_frame_eq(frame.as_deref(), Path::new("tests/lib/draw/expected.png"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn capture_frame_of_pretty_program() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/draw"), false)?.0;
+    let mut runner = Runner::new(program.into_pretty()).await?;
+    runner.resize(320, 240);
+    runner.run_step()?;
+    let frame = runner.capture_frame();
+    assert_frame_eq(frame.as_deref(), Path::new("tests/lib/draw/expected.png"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_frame() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/draw"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.resize(320, 240);
+    runner.run_step()?;
+    let out_path = Path::new("tests/lib/out_frame.png");
+    let result = runner.save_frame(out_path);
+    let frame = read_png(out_path);
+    if out_path.is_file() {
+        _ = fs::remove_file(out_path);
+    }
+    result?;
+    assert_frame_eq(Some(&frame), Path::new("tests/lib/draw/expected.png"));
+    Ok(())
+}
+
+#[tokio::test]
+#[expect(clippy::expect_used)]
+async fn save_frame_in_non_existing_folder() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/draw"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    let result = runner.save_frame(Path::new("tests/missing/out.png"));
+    let errors = result.expect_err("saving should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0]
+            .to_string()
+            .starts_with("error: cannot write \"tests/missing/out.png\": ")
+    );
+    Ok(())
+}
+
+const MAX_CHANNEL_DIFF: u8 = 2;
+
+fn assert_frame_eq(frame: Option<&[u8]>, expected_path: &Path) {
+    let frame = frame.unwrap_or_default();
+    let expected = read_png(expected_path);
+    assert_eq!(frame.len(), expected.len());
+    for (index, (actual, expected)) in frame.iter().zip(&expected).enumerate() {
+        assert!(
+            actual.abs_diff(*expected) <= MAX_CHANNEL_DIFF,
+            "byte {index}: {actual} != {expected}"
+        );
+    }
+}
+
+#[expect(clippy::expect_used)]
+fn read_png(path: &Path) -> Vec<u8> {
+    let file = BufReader::new(fs::File::open(path).expect("cannot open image"));
+    let mut reader = png::Decoder::new(file)
+        .read_info()
+        .expect("cannot read image");
+    let mut frame = vec![0; reader.output_buffer_size().expect("too big image")];
+    let info = reader.next_frame(&mut frame).expect("cannot decode image");
+    frame.truncate(info.buffer_size());
+    frame
+}
+
+#[tokio::test]
+async fn run_program_with_input() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/input"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    let input = runner.input_mut();
+    assert!(input.set("key_space", 1));
+    assert!(input.set("mouse_x", 12));
+    assert!(input.set("mouse_y", -3));
+    assert!(input.set("mouse_right", 5));
+    assert!(input.set("gamepad_left_y", -1000));
+    assert!(!input.set("unknown_input", 1));
+    assert_eq!(runner.input().get("key_space"), Some(1));
+    assert_eq!(runner.input().get("mouse_right"), Some(1));
+    assert_eq!(runner.input().get("key_a"), Some(0));
+    assert_eq!(runner.input().get("unknown_input"), None);
+    runner.run_step()?;
+    assert_eq!(
+        runner.read_variable("main:pressed_key"),
+        Some(GpuValue::I32(1))
+    );
+    assert_eq!(
+        runner.read_variable("main:released_key"),
+        Some(GpuValue::I32(0))
+    );
+    assert_eq!(
+        runner.read_variable("main:position_x"),
+        Some(GpuValue::I32(12))
+    );
+    assert_eq!(
+        runner.read_variable("main:position_y"),
+        Some(GpuValue::I32(-3))
+    );
+    assert_eq!(runner.read_variable("main:button"), Some(GpuValue::I32(1)));
+    assert_eq!(
+        runner.read_variable("main:axis"),
+        Some(GpuValue::I32(-1000))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn render_program_with_input() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/input"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.resize(40, 30);
+    runner.run_step()?;
+    runner.input_mut().set("mouse_x", 20);
+    runner.input_mut().set("mouse_y", 10);
+    runner.run_step()?;
+    let frame = runner.capture_frame().unwrap_or_default();
+    let pixel = |column: usize, row: usize| {
+        let offset = (row * 40 + column) * 4;
+        &frame[offset..offset + 4]
+    };
+    assert_eq!(pixel(25, 15), [255, 255, 255, 255]);
+    assert_eq!(pixel(5, 5), [0, 0, 0, 255]);
+    Ok(())
+}
+
+#[test]
+fn apply_input_script() -> Result<(), Vec<Log>> {
+    let script = InputScript::load(Path::new("tests/lib/input/script.txt"))?;
+    let mut input = Input::default();
+    script.apply(0, &mut input);
+    assert_eq!(input.get("key_space"), Some(1));
+    assert_eq!(input.get("mouse_x"), Some(0));
+    assert_eq!(input.get("mouse_y"), Some(-4));
+    assert_eq!(input.get("gamepad_left_y"), Some(1000));
+    script.apply(1, &mut input);
+    assert_eq!(input.get("key_space"), Some(0));
+    assert_eq!(input.get("mouse_x"), Some(30));
+    assert_eq!(input.get("mouse_y"), Some(-4));
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn load_invalid_input_script() {
+    let result = InputScript::load(Path::new("tests/lib/input/invalid_script.txt"));
+    let errors = result.expect_err("loading should generate errors");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors[0].to_string(),
+        "error: invalid input script line `0 unknown_input 1` \
+        (at tests/lib/input/invalid_script.txt:2:1)\n"
+    );
+    assert_eq!(
+        errors[1].to_string(),
+        "error: invalid input script line `invalid line` \
+        (at tests/lib/input/invalid_script.txt:3:3)\n"
+    );
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn load_missing_input_script() {
+    let result = InputScript::load(Path::new("tests/missing/script.txt"));
+    let errors = result.expect_err("loading should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0]
+            .to_string()
+            .starts_with("error: cannot read \"tests/missing/script.txt\": ")
+    );
+}
+
+#[tokio::test]
+async fn run_program_with_fixed_timestep() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.resize(8, 3);
+    runner.set_fixed_timestep(Some(Duration::from_secs(2)));
+    for _ in 0..3 {
+        runner.run_step()?;
+    }
+    assert_eq!(runner.frame_index(), 3);
+    assert_eq!(
+        runner.read_variable("main:first_frame_index"),
+        Some(GpuValue::I32(0))
+    );
+    assert_eq!(
+        runner.read_variable("main:first_delta"),
+        Some(GpuValue::F32(2.))
+    );
+    assert_eq!(
+        runner.read_variable("main:first_elapsed"),
+        Some(GpuValue::F32(0.))
+    );
+    let frame = runner.capture_frame().unwrap_or_default();
+    let red_row = &frame[..8 * 4];
+    let green_row = &frame[8 * 4..16 * 4];
+    let blue_row = &frame[16 * 4..];
+    assert_eq!(red_row[2 * 4..3 * 4], [255, 0, 0, 255]);
+    assert_eq!(green_row[2 * 4..3 * 4], [0, 255, 0, 255]);
+    assert_eq!(blue_row[4 * 4..5 * 4], [0, 0, 255, 255]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_program_with_real_time() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.run_step()?;
+    runner.run_step()?;
+    assert_eq!(runner.frame_index(), 2);
+    assert_eq!(
+        runner.read_variable("main:first_delta"),
+        Some(GpuValue::F32(0.))
+    );
+    assert_eq!(
+        runner.read_variable("main:first_elapsed"),
+        Some(GpuValue::F32(0.))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_variable() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.run_step()?;
+    runner.write_variable("main:first_frame_index", GpuValue::I32(42))?;
+    runner.write_variable("main:first_delta", GpuValue::F32(0.5))?;
+    runner.run_step()?;
+    assert_eq!(
+        runner.read_variable("main:first_frame_index"),
+        Some(GpuValue::I32(42))
+    );
+    assert_eq!(
+        runner.read_variable("main:first_delta"),
+        Some(GpuValue::F32(0.5))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[expect(clippy::expect_used)]
+async fn write_invalid_variable() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    let errors = runner
+        .write_variable("main:missing", GpuValue::I32(1))
+        .expect_err("writing should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "error: `main:missing` variable not found\n"
+    );
+    let errors = runner
+        .write_variable("main:first_delta", GpuValue::I32(1))
+        .expect_err("writing should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "error: `i32` value written in `main:first_delta` variable of type `f32`\n"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_variables() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.run_step()?;
+    let values = runner.read_variables(&["root:_root_value", "module:invalid"]);
+    assert_eq!(values.len(), 1);
+    assert_eq!(values["root:_root_value"], GpuValue::I32(2));
+    let values = runner.snapshot();
+    assert_eq!(values.len(), 2);
+    assert_eq!(values["root:_root_value"], GpuValue::I32(2));
+    assert_eq!(values["inner.inner2.inner:_inner_value"], GpuValue::I32(1));
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_variables_without_blocking() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.run_step()?;
+    let mut pending = runner.start_snapshot();
+    let values = loop {
+        if let Some(values) = pending.try_finish() {
+            break values;
+        }
+        tokio::task::yield_now().await;
+    };
+    assert_eq!(values.len(), 2);
+    assert_eq!(values["root:_root_value"], GpuValue::I32(2));
+    assert!(pending.try_finish().is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_variables_without_buffer() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/draw_only"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.run_step()?;
+    assert!(runner.snapshot().is_empty());
+    let mut pending = runner.start_read_variables(&["main:missing"]);
+    assert_eq!(pending.try_finish().map(|values| values.len()), Some(0));
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_and_load_state() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut runner = Runner::new(program.clone()).await?;
+    runner.set_fixed_timestep(Some(Duration::from_secs(1)));
+    runner.run_step()?;
+    runner.write_variable("main:first_frame_index", GpuValue::I32(7))?;
+    let out_path = Path::new("tests/lib/out_state.json");
+    let result = runner.save_state(out_path);
+    let mut runner = Runner::new(program).await?;
+    let warnings = runner.load_state(out_path);
+    if out_path.is_file() {
+        _ = fs::remove_file(out_path);
+    }
+    result?;
+    assert!(warnings?.is_empty());
+    runner.run_step()?;
+    assert_eq!(
+        runner.read_variable("main:first_frame_index"),
+        Some(GpuValue::I32(7))
+    );
+    assert_eq!(
+        runner.read_variable("main:first_delta"),
+        Some(GpuValue::F32(1.))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_and_load_state_with_non_finite_values() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut runner = Runner::new(program.clone()).await?;
+    runner.run_step()?;
+    runner.write_variable("main:first_delta", GpuValue::F32(f32::NAN))?;
+    runner.write_variable("main:first_elapsed", GpuValue::F32(f32::NEG_INFINITY))?;
+    let out_path = Path::new("tests/lib/out_state_non_finite.json");
+    let result = runner.save_state(out_path);
+    let mut runner = Runner::new(program).await?;
+    let warnings = runner.load_state(out_path);
+    if out_path.is_file() {
+        _ = fs::remove_file(out_path);
+    }
+    result?;
+    assert!(warnings?.is_empty());
+    assert!(matches!(
+        runner.read_variable("main:first_delta"),
+        Some(GpuValue::F32(value)) if value.is_nan()
+    ));
+    assert_eq!(
+        runner.read_variable("main:first_elapsed"),
+        Some(GpuValue::F32(f32::NEG_INFINITY))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn load_state_of_changed_program() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    let warnings = runner.load_state(Path::new("tests/lib/state/changed.json"))?;
+    assert_eq!(
+        warnings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        [
+            "warning: `main:first_elapsed` variable not found in state\n  \
+            --> info: variable is set to zero\n",
+            "warning: `main:first_frame_index` variable not restored from state\n  \
+            --> info: saved value has type `f32`, but variable has type `i32`\n",
+            "warning: `main:removed` variable in state not found in program\n",
+        ]
+    );
+    runner.run_step()?;
+    assert_eq!(
+        runner.read_variable("main:first_delta"),
+        Some(GpuValue::F32(0.25))
+    );
+    assert_eq!(
+        runner.read_variable("main:first_frame_index"),
+        Some(GpuValue::I32(0))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[expect(clippy::expect_used)]
+async fn load_invalid_state() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/time"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    let errors = runner
+        .load_state(Path::new("tests/lib/state/invalid.json"))
+        .expect_err("loading should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0]
+            .to_string()
+            .starts_with("error: invalid state file \"tests/lib/state/invalid.json\": ")
+    );
+    let errors = runner
+        .load_state(Path::new("tests/missing/state.json"))
+        .expect_err("loading should generate errors");
+    assert!(
+        errors[0]
+            .to_string()
+            .starts_with("error: cannot read \"tests/missing/state.json\": ")
+    );
+    let errors = runner
+        .save_state(Path::new("tests/missing/state.json"))
+        .expect_err("saving should generate errors");
+    assert!(
+        errors[0]
+            .to_string()
+            .starts_with("error: cannot write \"tests/missing/state.json\": ")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[expect(clippy::expect_used)]
+async fn create_runner_with_invalid_shader() -> Result<(), Vec<Log>> {
+    let (mut program, _) = gpex::compile(Path::new("tests/lib/draw"), false)?;
+    program.init_shader = program.init_shader.replace("i32(20)", "u32(20)");
+    let result = Runner::new(program).await;
+    let errors = result.expect_err("runner creation should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].level, LogLevel::Error);
+    assert_eq!(errors[0].message, "GPU error during init shader creation");
+    let source_inner = errors[0].inner.last().expect("missing inner log");
+    assert_eq!(
+        source_inner.message,
+        "shader code generated from `main:size`"
+    );
+    assert_eq!(
+        source_inner.location.as_ref().map(ToString::to_string),
+        Some("tests/lib/draw/main.gpex:1:5".into())
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[expect(clippy::expect_used)]
+async fn create_runner_with_too_large_buffer() -> Result<(), Vec<Log>> {
+    let (mut program, _) = gpex::compile(Path::new("tests/lib/draw"), false)?;
+    program.buffer.parts[0].size = 0xFFFF_FFF0;
+    let result = Runner::new(program).await;
+    let errors = result.expect_err("runner creation should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].level, LogLevel::Error);
+    assert_eq!(
+        errors[0].message,
+        "graphic device doesn't support the limits required by the program"
+    );
+    assert!(
+        errors[0].inner[0]
+            .message
+            .starts_with("`max_storage_buffer_binding_size` is ")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_program_with_existing_device() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let owner = Runner::new(program.clone()).await?;
+    let (device, queue) = (
+        owner.backend().device().clone(),
+        owner.backend().queue().clone(),
+    );
+    let mut runner = Runner::with_device(program, device.clone(), queue.clone())?;
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    runner.record_step(&mut encoder)?;
+    assert_eq!(
+        runner.read_variable("root:_root_value"),
+        Some(GpuValue::I32(0))
+    );
+    queue.submit(Some(encoder.finish()));
+    assert_eq!(runner.frame_index(), 1);
+    assert_eq!(
+        runner.read_variable("root:_root_value"),
+        Some(GpuValue::I32(2))
+    );
+    let field = &runner.buffer_layout().fields["root:_root_value"];
+    assert_eq!(field.size, 4);
+    assert_eq!(runner.buffers().len(), 1);
+    assert!(runner.buffers()[0].size() >= u64::from(field.offset + 4));
+    Ok(())
+}
+
+#[tokio::test]
+#[expect(clippy::expect_used)]
+async fn create_runner_with_unsupported_limits() -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.limits.max_storage_buffer_binding_size = 4;
+    options.limits.max_storage_buffers_per_shader_stage = u32::MAX;
+    let program = gpex::compile_with_options(Path::new("tests/lib/split"), options)?.0;
+    assert_eq!(program.limits, options.limits);
+    let (device, queue) = downlevel_device().await;
+    let result = Runner::with_device(program, device, queue);
+    let errors = result.expect_err("runner creation should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "graphic device doesn't support the limits required by the program"
+    );
+    assert_eq!(errors[0].inner.len(), 1);
+    assert_eq!(
+        errors[0].inner[0].message,
+        "`max_storage_buffers_per_shader_stage` is 4 on the device, but 6 is required"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_program_with_device_of_lower_limits() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let (device, queue) = downlevel_device().await;
+    let mut runner = Runner::with_device(program, device, queue)?;
+    runner.run_step()?;
+    assert_eq!(
+        runner.read_variable("root:_root_value"),
+        Some(GpuValue::I32(2))
+    );
+    Ok(())
+}
+
+#[expect(clippy::expect_used)]
+async fn downlevel_device() -> (wgpu::Device, wgpu::Queue) {
+    let adapters = gpex::available_adapters(&AdapterOptions::default()).await;
+    let descriptor = wgpu::DeviceDescriptor {
+        required_limits: wgpu::Limits::downlevel_defaults(),
+        ..wgpu::DeviceDescriptor::default()
+    };
+    adapters[0]
+        .request_device(&descriptor)
+        .await
+        .expect("device should be created")
+}
+
+#[tokio::test]
+async fn run_program_with_adapter_options() -> Result<(), Vec<Log>> {
+    let adapters = gpex::available_adapters(&AdapterOptions::default()).await;
+    let name = adapters[0].get_info().name;
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut options = AdapterOptions::default();
+    options.name = Some(name.to_uppercase());
+    options.power_preference = wgpu::PowerPreference::HighPerformance;
+    let mut runner = Runner::with_adapter(program, &options).await?;
+    runner.run_step()?;
+    assert_eq!(
+        runner.read_variable("root:_root_value"),
+        Some(GpuValue::I32(2))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_runner_with_unknown_adapter() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut options = AdapterOptions::default();
+    options.name = Some("unknown adapter".into());
+    let result = Runner::with_adapter(program, &options).await;
+    let Err(errors) = result else {
+        panic!("runner should not be created");
+    };
+    assert_eq!(
+        errors[0].message,
+        "no supported graphic adapter matching `unknown adapter`"
+    );
+    assert!(!errors[0].inner.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_program_with_split_buffer() -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.limits.max_storage_buffer_binding_size = 8;
+    let program = gpex::compile_with_options(Path::new("tests/lib/split"), options)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.run_step()?;
+    runner.render();
+    runner.write_variable("main:fifth", GpuValue::I32(7))?;
+    let values = runner.snapshot();
+    assert_eq!(values.len(), 5);
+    assert_eq!(values["main:first"], GpuValue::I32(1));
+    assert_eq!(values["main:second"], GpuValue::I32(2));
+    assert_eq!(values["main:third"], GpuValue::I32(3));
+    assert_eq!(values["main:fourth"], GpuValue::I32(1));
+    assert_eq!(values["main:fifth"], GpuValue::I32(7));
+    Ok(())
+}
+
+#[tokio::test]
+#[expect(clippy::expect_used)]
+async fn run_program_with_failed_assert() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/assert"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    let errors = runner.run_step().expect_err("step should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "error: assertion failed: value is positive (at tests/lib/assert/main.gpex:6:1)\n"
+    );
+    assert_eq!(runner.read_variable("main:value"), Some(GpuValue::I32(2)));
+    runner.run_step()?;
+    Ok(())
+}
+
+#[tokio::test]
+#[expect(clippy::expect_used)]
+async fn run_program_with_only_asserts() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/assert_only"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    let errors = runner.run_step().expect_err("step should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "assertion failed: always fails");
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_program_with_debug_prints() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/debug_print"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.set_fixed_timestep(Some(Duration::from_millis(500)));
+    runner.run_step()?;
+    let logs = runner.take_debug_logs();
+    assert_eq!(logs.len(), 3);
+    assert_eq!(
+        logs[0].to_string(),
+        "info: value = 2 (at tests/lib/debug_print/main.gpex:4:1)\n"
+    );
+    assert_eq!(logs[1].message, "delta = 0.5, frame = 0");
+    assert_eq!(logs[2].message, "no argument");
+    assert!(runner.take_debug_logs().is_empty());
+    runner.run_step()?;
+    assert!(runner.take_debug_logs().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_program_with_dropped_debug_prints() -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.debug_print_capacity = 10;
+    let path = Path::new("tests/lib/debug_print_overflow");
+    let program = gpex::compile_with_options(path, options)?.0;
+    assert_eq!(program.debug_print_capacity, 10);
+    let mut runner = Runner::new(program).await?;
+    runner.run_step()?;
+    let logs = runner.take_debug_logs();
+    assert_eq!(logs.len(), 3);
+    assert_eq!(logs[1].message, "1 1 1");
+    assert_eq!(
+        logs[2].to_string(),
+        "warning: 1 debug print(s) dropped because the debug buffer is full\n  \
+        --> info: debug buffer stores at most 10 words during initialization\n"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[expect(clippy::expect_used)]
+async fn profile_program() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut options = AdapterOptions::default();
+    options.is_profiling_enabled = true;
+    let mut runner = Runner::with_adapter(program, &options).await?;
+    runner.run_step()?;
+    runner.run_step()?;
+    let features = runner.backend().device().features();
+    if !features.contains(wgpu::Features::TIMESTAMP_QUERY) {
+        eprintln!("profiling checks skipped: timestamp queries not supported by the adapter");
+        assert!(runner.backend().profile().is_none());
+        return Ok(());
+    }
+    let profile = runner
+        .backend()
+        .profile()
+        .expect("profiling should be enabled");
+    assert_eq!(profile.samples.len(), 1);
+    assert_eq!(profile.samples[0].shader, "init shader");
+    assert_eq!(profile.samples[0].start, Duration::ZERO);
+    let timings = profile.shader_timings();
+    assert_eq!(timings.len(), 1);
+    assert_eq!(timings[0].shader, "init shader");
+    assert_eq!(timings[0].count, 1);
+    assert_eq!(timings[0].min, profile.samples[0].duration);
+    assert_eq!(timings[0].avg, profile.samples[0].duration);
+    assert_eq!(timings[0].max, profile.samples[0].duration);
+    let out_path = Path::new("tests/lib/out_trace.json");
+    let result = profile.save_chrome_trace(out_path);
+    let trace = fs::read_to_string(out_path);
+    if out_path.is_file() {
+        _ = fs::remove_file(out_path);
+    }
+    result?;
+    assert_eq!(trace.ok(), Some(profile.to_chrome_trace()));
+    assert!(
+        profile
+            .to_chrome_trace()
+            .contains("\"name\": \"init shader\"")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn run_program_without_profiling() -> Result<(), Vec<Log>> {
+    let program = gpex::compile(Path::new("tests/lib/valid"), false)?.0;
+    let mut runner = Runner::new(program).await?;
+    runner.run_step()?;
+    assert_eq!(runner.backend().profile(), None);
+    Ok(())
+}
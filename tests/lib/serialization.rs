@@ -1,12 +1,16 @@
-use gpex::{Log, LogLevel};
+use flate2::Compression;
+use flate2::write::DeflateEncoder;
+use gpex::{CompileOptions, CompiledFormat, Log, LogLevel};
 use std::fs;
+use std::io;
+use std::io::Read;
 use std::path::Path;
 
 #[test]
 fn save_and_load_program() -> Result<(), Vec<Log>> {
     let (program, _) = gpex::compile(Path::new("tests/lib/valid"), false)?;
     let out_path = Path::new("tests/lib/out1.json");
-    gpex::save_compiled(&program, out_path)?;
+    gpex::save_compiled(&program, out_path, CompiledFormat::Json)?;
     let result = gpex::load_compiled(out_path);
     if out_path.is_file() {
         _ = fs::remove_file(out_path);
@@ -19,7 +23,11 @@ fn save_and_load_program() -> Result<(), Vec<Log>> {
 #[expect(clippy::expect_used)]
 fn save_in_non_existing_folder() -> Result<(), Vec<Log>> {
     let (program, _) = gpex::compile(Path::new("tests/lib/valid"), false)?;
-    let result = gpex::save_compiled(&program, Path::new("tests/missing/out.json"));
+    let result = gpex::save_compiled(
+        &program,
+        Path::new("tests/missing/out.json"),
+        CompiledFormat::Json,
+    );
     let errors = result.expect_err("saving should generate errors");
     assert_eq!(errors.len(), 1);
     assert_eq!(errors[0].level, LogLevel::Error);
@@ -63,3 +71,196 @@ fn load_invalid_file() {
         "error: invalid compiled program \"tests/lib/main.rs\"\n"
     );
 }
+
+#[test]
+fn save_and_load_binary_program() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/draw"), false)?;
+    let json_path = Path::new("tests/lib/out3.json");
+    let binary_path = Path::new("tests/lib/out3.bin");
+    let compressed_path = Path::new("tests/lib/out3.bin.gz");
+    gpex::save_compiled(&program, json_path, CompiledFormat::Json)?;
+    gpex::save_compiled(&program, binary_path, CompiledFormat::Binary)?;
+    gpex::save_compiled(&program, compressed_path, CompiledFormat::CompressedBinary)?;
+    let sizes = [json_path, binary_path, compressed_path]
+        .map(|path| fs::metadata(path).map_or(0, |metadata| metadata.len()));
+    let binary_result = gpex::load_compiled(binary_path);
+    let compressed_result = gpex::load_compiled(compressed_path);
+    for path in [json_path, binary_path, compressed_path] {
+        if path.is_file() {
+            _ = fs::remove_file(path);
+        }
+    }
+    assert!(sizes[1] < sizes[0]);
+    assert!(sizes[2] < sizes[1]);
+    for loaded in [binary_result?, compressed_result?] {
+        assert_eq!(loaded, program);
+    }
+    Ok(())
+}
+
+#[test]
+fn save_and_load_all_program_fields() -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.limits.max_storage_buffer_binding_size = 8;
+    let programs = [
+        gpex::compile(Path::new("tests/lib/assert"), false)?.0,
+        gpex::compile(Path::new("tests/lib/debug_print"), false)?.0,
+        gpex::compile(Path::new("tests/lib/draw"), false)?.0,
+        gpex::compile_with_options(Path::new("tests/lib/split"), options)?.0,
+    ];
+    assert!(!programs[0].asserts.is_empty());
+    assert!(!programs[1].debug_prints.is_empty());
+    assert!(!programs[2].draw_shaders.is_empty());
+    assert!(programs[3].buffer.parts.len() > 1);
+    let path = Path::new("tests/lib/out4");
+    for program in programs {
+        for format in [
+            CompiledFormat::Json,
+            CompiledFormat::Binary,
+            CompiledFormat::CompressedBinary,
+        ] {
+            gpex::save_compiled(&program, path, format)?;
+            let result = gpex::load_compiled(path);
+            if path.is_file() {
+                _ = fs::remove_file(path);
+            }
+            assert_eq!(result?, program);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn load_corrupted_binary_file() {
+    let result = gpex::load_compiled(Path::new("tests/lib/compiled/corrupted.bin"));
+    let errors = result.expect_err("loading should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "error: invalid compiled program \"tests/lib/compiled/corrupted.bin\"\n  \
+        --> info: binary content is corrupted\n"
+    );
+}
+
+#[test]
+#[expect(clippy::expect_used, clippy::unwrap_used)]
+fn load_binary_file_with_too_large_decompressed_content() {
+    let mut bytes = b"GPEXBIN\0".to_vec();
+    bytes.extend(1_u32.to_le_bytes());
+    bytes.push(1);
+    bytes.extend(5_u32.to_le_bytes());
+    bytes.extend(b"0.1.0");
+    let mut encoder = DeflateEncoder::new(bytes, Compression::fast());
+    io::copy(&mut io::repeat(0).take(65 * 1024 * 1024), &mut encoder).unwrap();
+    let path = Path::new("tests/lib/out5.bin");
+    fs::write(path, encoder.finish().unwrap()).unwrap();
+    let result = gpex::load_compiled(path);
+    if path.is_file() {
+        _ = fs::remove_file(path);
+    }
+    let errors = result.expect_err("loading should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].inner[0].message, "binary content is corrupted");
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn load_binary_file_with_other_format_version() {
+    let result = gpex::load_compiled(Path::new("tests/lib/compiled/old_version.bin"));
+    let errors = result.expect_err("loading should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "compiled program \"tests/lib/compiled/old_version.bin\" has unsupported format version 0"
+    );
+}
+
+#[test]
+fn save_and_load_source_hash() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/valid"), false)?;
+    let (other_program, _) = gpex::compile(Path::new("tests/lib/draw"), false)?;
+    assert_ne!(program.source_hash, other_program.source_hash);
+    let out_path = Path::new("tests/lib/out2.json");
+    gpex::save_compiled(&program, out_path, CompiledFormat::Json)?;
+    let result = gpex::load_compiled(out_path);
+    if out_path.is_file() {
+        _ = fs::remove_file(out_path);
+    }
+    let loaded_program = result?;
+    assert_eq!(loaded_program.source_hash, program.source_hash);
+    assert!(loaded_program.is_compiled_from(Path::new("tests/lib/valid"))?);
+    assert!(!loaded_program.is_compiled_from(Path::new("tests/lib/draw"))?);
+    let (recompiled_program, _) = gpex::compile(Path::new("tests/lib/valid"), false)?;
+    assert_eq!(recompiled_program.source_hash, program.source_hash);
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn check_source_of_program_with_missing_folder() -> Result<(), Vec<Log>> {
+    let (program, _) = gpex::compile(Path::new("tests/lib/valid"), false)?;
+    let result = program.is_compiled_from(Path::new("tests/lib/missing"));
+    let errors = result.expect_err("checking should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0]
+            .to_string()
+            .starts_with("error: cannot read \"tests/lib/missing\": ")
+    );
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn load_file_with_other_format_version() {
+    let result = gpex::load_compiled(Path::new("tests/lib/compiled/old_version.json"));
+    let errors = result.expect_err("loading should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "error: compiled program \"tests/lib/compiled/old_version.json\" \
+        has unsupported format version 0\n  \
+        --> info: program compiled with gpex 0.0.1, but format version 1 \
+        is expected by gpex 0.1.0, recompile the program\n"
+    );
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn load_file_with_invalid_shaders() {
+    let result = gpex::load_compiled(Path::new("tests/lib/compiled/invalid_shader.json"));
+    let errors = result.expect_err("loading should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "invalid compiled program \"tests/lib/compiled/invalid_shader.json\""
+    );
+    assert_eq!(errors[0].inner.len(), 2);
+    assert_eq!(
+        errors[0].inner[0].message,
+        "invalid init shader: the type of `x` is expected to be `i32`, but got `{AbstractFloat}` \
+        in `fn main() { let x: i32 = 1.5; }`"
+    );
+    assert!(
+        errors[0].inner[1]
+            .message
+            .starts_with("invalid draw shader #0: ")
+    );
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn load_file_with_inconsistent_buffer() {
+    let result = gpex::load_compiled(Path::new("tests/lib/compiled/invalid_buffer.json"));
+    let errors = result.expect_err("loading should generate errors");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "error: invalid compiled program \"tests/lib/compiled/invalid_buffer.json\"\n  \
+        --> info: buffer part #1 is outside of the buffer\n  \
+        --> info: buffer field `inner.inner2.inner:_inner_value` is outside of the buffer parts\n  \
+        --> info: buffer field `root:_root_value` is outside of the buffer parts\n  \
+        --> info: draw shaders and draw source maps have different counts\n"
+    );
+}
@@ -0,0 +1,69 @@
+use gpex::{CompileOptions, Interpreter, Log, LogLevel, Runner, TestSuite};
+use std::path::Path;
+
+#[tokio::test]
+async fn run_tests() -> Result<(), Vec<Log>> {
+    let (suite, warnings) =
+        TestSuite::load(Path::new("tests/lib/testing"), CompileOptions::default())?;
+    assert!(warnings.is_empty());
+    assert_eq!(suite.len(), 4);
+    let mut runner = Runner::new(suite.program().clone()).await?;
+    let report = suite.run(&mut runner, 2);
+    assert!(!report.is_success());
+    assert!(report.errors.is_empty());
+    assert_eq!(report.failure_count(), 1);
+    assert_eq!(report.results.len(), 4);
+    assert_eq!(report.results[0].variable_path, "inner.module:value");
+    assert!(report.results[0].is_success());
+    assert_eq!(
+        report.results[1].to_log().to_string(),
+        "info: `main:_passed` test passed (at tests/lib/testing/main.gpex:3:5)\n"
+    );
+    assert_eq!(report.results[2].to_log().level, LogLevel::Error);
+    assert_eq!(
+        report.results[2].to_log().to_string(),
+        "error: `main:_failed` test failed (at tests/lib/testing/main.gpex:4:5)\n  \
+        --> info: expected value: `3`\n  \
+        --> info: actual value: `2`\n"
+    );
+    assert_eq!(
+        report.results[3].location().to_string(),
+        "tests/lib/testing/main.gpex:5:9"
+    );
+    assert!(report.results[3].is_success());
+    Ok(())
+}
+
+#[test]
+fn run_tests_on_cpu() -> Result<(), Vec<Log>> {
+    let (suite, _) = TestSuite::load(Path::new("tests/lib/testing"), CompileOptions::default())?;
+    let mut interpreter = Interpreter::new(suite.program().clone())?;
+    let report = suite.run(&mut interpreter, 1);
+    assert_eq!(report.failure_count(), 1);
+    assert_eq!(report.results[2].actual.as_deref(), Some("2"));
+    assert_eq!(report.results[2].expected, "3");
+    Ok(())
+}
+
+#[test]
+fn run_tests_with_failed_step() -> Result<(), Vec<Log>> {
+    let (suite, _) = TestSuite::load(
+        Path::new("tests/lib/unsupported"),
+        CompileOptions::default(),
+    )?;
+    assert!(suite.is_empty());
+    let mut interpreter = Interpreter::new(suite.program().clone())?;
+    let report = suite.run(&mut interpreter, 3);
+    assert!(!report.is_success());
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.failure_count(), 0);
+    Ok(())
+}
+
+#[test]
+#[expect(clippy::expect_used)]
+fn load_invalid_tests() {
+    let errors = TestSuite::load(Path::new("tests/lib/error"), CompileOptions::default())
+        .expect_err("loading should fail");
+    assert!(!errors.is_empty());
+}
@@ -40,11 +40,46 @@ fn compile_with_constant_errors() -> io::Result<()> {
     compile_and_check_logs(Path::new("tests/logs/error_constants"))
 }
 
+#[test]
+fn compile_with_call_errors() -> io::Result<()> {
+    compile_and_check_logs(Path::new("tests/logs/error_calls"))
+}
+
+#[test]
+fn compile_with_draw_errors() -> io::Result<()> {
+    compile_and_check_logs(Path::new("tests/logs/error_draws"))
+}
+
+#[test]
+fn compile_with_assert_errors() -> io::Result<()> {
+    compile_and_check_logs(Path::new("tests/logs/error_asserts"))
+}
+
+#[test]
+fn compile_with_debug_print_errors() -> io::Result<()> {
+    compile_and_check_logs(Path::new("tests/logs/error_debug_prints"))
+}
+
+#[test]
+fn compile_with_extern_function_errors() -> io::Result<()> {
+    compile_and_check_logs(Path::new("tests/logs/error_extern_functions"))
+}
+
+#[test]
+fn compile_with_extern_variable_errors() -> io::Result<()> {
+    compile_and_check_logs(Path::new("tests/logs/error_extern_variables"))
+}
+
 #[test]
 fn compile_with_unused_warnings() -> io::Result<()> {
     compile_and_check_logs(Path::new("tests/logs/warning_unused"))
 }
 
+#[test]
+fn compile_with_ambiguity_warnings() -> io::Result<()> {
+    compile_and_check_logs(Path::new("tests/logs/warning_ambiguity"))
+}
+
 #[test]
 fn compile_with_naming_warnings() -> io::Result<()> {
     compile_and_check_logs(Path::new("tests/logs/warning_naming"))
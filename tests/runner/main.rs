@@ -1,82 +1,80 @@
 //! Tests for runner.
 
-use gpex::{Log, Runner};
+use gpex::{CompileOptions, GpuValue, Interpreter, Log, ProgramRunner, Runner, TestSuite};
 use itertools::Itertools;
-use regex::Regex;
-use std::ffi::OsStr;
-use std::path::{Path, PathBuf};
-use std::{fs, io};
+use std::collections::HashMap;
+use std::path::Path;
+
+// GPU math functions are allowed to be less precise than CPU ones
+const F32_TOLERANCE: f32 = 1e-5;
 
 #[tokio::test]
-async fn run_with_syntax_specificities() -> Result<(), Error> {
+async fn run_with_syntax_specificities() -> Result<(), Vec<Log>> {
     compile_and_run(Path::new("tests/runner/syntax"), true).await
 }
 
 #[tokio::test]
-async fn run_with_imports() -> Result<(), Error> {
+async fn run_with_imports() -> Result<(), Vec<Log>> {
     compile_and_run(Path::new("tests/runner/imports"), false).await
 }
 
 #[tokio::test]
-async fn run_with_expressions() -> Result<(), Error> {
+async fn run_with_expressions() -> Result<(), Vec<Log>> {
     compile_and_run(Path::new("tests/runner/expressions"), true).await
 }
 
 #[tokio::test]
-async fn run_with_prelude() -> Result<(), Error> {
+async fn run_with_prelude() -> Result<(), Vec<Log>> {
     compile_and_run(Path::new("tests/runner/prelude"), true).await
 }
 
-async fn compile_and_run(path: &Path, is_warning_treated_as_error: bool) -> Result<(), Error> {
-    let (program, _) = gpex::compile(path, is_warning_treated_as_error).map_err(Error::Gpex)?;
-    let mut runner = Runner::new(program).await.map_err(Error::Gpex)?;
-    runner.run_step();
-    check_global_vars(path, path, &runner)?;
-    Ok(())
-}
-
-fn check_global_vars(folder_path: &Path, root_path: &Path, runner: &Runner) -> Result<(), Error> {
-    let expected_regex = Regex::new(r"var (\w+) = .* // expected: (.+)").map_err(Error::Regex)?;
-    for entry in folder_path.read_dir().map_err(Error::Io)? {
-        let entry = entry.map_err(Error::Io)?;
-        let path = entry.path();
-        let file_type = entry.file_type().map_err(Error::Io)?;
-        if file_type.is_dir() {
-            check_global_vars(&path, root_path, runner)?;
-        } else if path.extension() == Some(OsStr::new("gpex")) {
-            let code = fs::read_to_string(&path).map_err(Error::Io)?;
-            let dot_path = to_dot_path(&path, root_path);
-            for capture in expected_regex.captures_iter(&code) {
-                let var_name = &capture[1];
-                let expected_value = &capture[2];
-                let var_path = format!("{dot_path}:{var_name}");
-                let actual_value = runner.read_variable(&var_path);
-                assert_eq!(
-                    Some(expected_value.into()),
-                    actual_value.map(|value| value.to_string()),
-                    "`{var_path}` variable"
-                );
-            }
+async fn compile_and_run(path: &Path, is_warning_treated_as_error: bool) -> Result<(), Vec<Log>> {
+    let mut options = CompileOptions::default();
+    options.is_warning_treated_as_error = is_warning_treated_as_error;
+    let (suite, _) = TestSuite::load(path, options)?;
+    assert!(!suite.is_empty());
+    let mut runner = Runner::new(suite.program().clone()).await?;
+    let mut interpreter = Interpreter::new(suite.program().clone())?;
+    for report in [suite.run(&mut runner, 1), suite.run(&mut interpreter, 1)] {
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        for result in &report.results {
+            assert!(result.is_success(), "{}", result.to_log());
         }
     }
+    assert_similar_snapshots(&runner.snapshot(), &interpreter.snapshot());
     Ok(())
 }
 
-fn to_dot_path(file_path: &Path, root_path: &Path) -> String {
-    file_path
-        .iter()
-        .skip(root_path.iter().count())
-        .collect::<PathBuf>()
-        .with_extension("")
-        .iter()
-        .map(|segment| segment.to_str().unwrap_or("<invalid>"))
-        .join(".")
+fn assert_similar_snapshots(
+    gpu_snapshot: &HashMap<String, GpuValue>,
+    cpu_snapshot: &HashMap<String, GpuValue>,
+) {
+    assert_eq!(
+        gpu_snapshot.keys().sorted().collect::<Vec<_>>(),
+        cpu_snapshot.keys().sorted().collect::<Vec<_>>()
+    );
+    for (path, gpu_value) in gpu_snapshot {
+        let cpu_value = &cpu_snapshot[path];
+        let are_similar = match (gpu_value, cpu_value) {
+            (&GpuValue::F32(gpu_value), &GpuValue::F32(cpu_value)) => {
+                are_similar_f32(gpu_value, cpu_value)
+            }
+            _ => gpu_value == cpu_value,
+        };
+        assert!(
+            are_similar,
+            "`{path}` variable: GPU value {gpu_value:?} != CPU value {cpu_value:?}"
+        );
+    }
 }
 
-#[derive(Debug)]
-#[expect(dead_code)] // variant data is useful for debugging tests
-enum Error {
-    Io(io::Error),
-    Regex(regex::Error),
-    Gpex(Vec<Log>),
+// the tolerance is relative for values far from zero, and absolute otherwise
+#[expect(clippy::float_cmp)] // infinite values are only similar to themselves
+fn are_similar_f32(first: f32, second: f32) -> bool {
+    if first.is_nan() || second.is_nan() {
+        first.is_nan() && second.is_nan()
+    } else {
+        first == second
+            || (first - second).abs() <= F32_TOLERANCE * first.abs().max(second.abs()).max(1.)
+    }
 }